@@ -105,6 +105,20 @@ pub enum CrudAction<T: CrudMainTrait> {
         action: Callback<(Option<T::ActionPayload>, Callback<Result<CrudActionAftermath, CrudActionAftermath>>)>,
         modal: Option<Box<Arc<dyn Fn(ModalGeneration<T>) -> Html>>>,
     },
+    /// An action operating on all entities currently selected in the list view.
+    /// Only shown while at least one entity is selected.
+    Batch {
+        id: &'static str,
+        name: String,
+        icon: Option<Bi>,
+        variant: Variant,
+        action: Callback<(
+            Vec<T::ReadModel>,
+            Option<T::ActionPayload>,
+            Callback<Result<CrudActionAftermath, CrudActionAftermath>>,
+        )>,
+        modal: Option<Box<Arc<dyn Fn(ModalGeneration<T>) -> Html>>>,
+    },
 }
 
 impl<T: CrudMainTrait> Debug for CrudAction<T> {
@@ -124,6 +138,29 @@ impl<T: CrudMainTrait> Debug for CrudAction<T> {
                 .field("icon", icon)
                 .field("variant", variant)
                 .finish(),
+            Self::Batch {
+                id,
+                name,
+                icon,
+                variant,
+                action: _,
+                modal: _,
+            } => f
+                .debug_struct("Batch")
+                .field("id", id)
+                .field("name", name)
+                .field("icon", icon)
+                .field("variant", variant)
+                .finish(),
+        }
+    }
+}
+
+impl<T: CrudMainTrait> CrudAction<T> {
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Custom { id, .. } => id,
+            Self::Batch { id, .. } => id,
         }
     }
 }
@@ -149,13 +186,44 @@ impl<T: CrudMainTrait> PartialEq for CrudAction<T> {
                     modal: _r_modal,
                 },
             ) => l_id == r_id && l_name == r_name && l_icon == r_icon && l_variant == r_variant,
+            (
+                Self::Batch {
+                    id: l_id,
+                    name: l_name,
+                    icon: l_icon,
+                    variant: l_variant,
+                    action: _l_action,
+                    modal: _l_modal,
+                },
+                Self::Batch {
+                    id: r_id,
+                    name: r_name,
+                    icon: r_icon,
+                    variant: r_variant,
+                    action: _r_action,
+                    modal: _r_modal,
+                },
+            ) => l_id == r_id && l_name == r_name && l_icon == r_icon && l_variant == r_variant,
+            _ => false,
         }
     }
 }
 
+/// Actions not operating on a batch of entities can leave out `entity_results` using `..Default::default()`.
+#[derive(Default)]
 pub struct CrudActionAftermath {
     pub show_toast: Option<Toast>,
     pub reload_data: bool,
+    /// Outcome of a batch action for each entity it operated on. Empty for other actions.
+    pub entity_results: Vec<EntityActionResult>,
+}
+
+/// Outcome of a batch action for a single entity.
+pub struct EntityActionResult {
+    /// Human readable identification of the entity, for example its id.
+    pub entity: String,
+    /// The reason of failure, if the action failed for this entity.
+    pub result: Result<(), String>,
 }
 
 /// Used to model entity action such as "Open edit view", ...
@@ -0,0 +1,540 @@
+use crudkit_condition::{
+    Condition, ConditionClause, ConditionClauseValue, ConditionElement, IntoAllEqualCondition,
+};
+use crudkit_id::{Id, IdField};
+use crudkit_shared::SaveResult;
+use time::macros::format_description;
+use tracing::warn;
+use yew::{html::ChildrenRenderer, prelude::*};
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use crate::{
+    crud_instance::Item,
+    crud_offset_datetime::{Day, Week},
+    crud_offset_datetime_date_selector::CrudOffsetDatetimeDateSelector,
+    crud_table::RowPermissions,
+    services::crud_rest_data_provider::{CrudRestDataProvider, ReadMany, UpdateOne},
+    types::{custom_field::CustomReadFields, permissions::EntityOperation},
+    DateTimeDisplay,
+};
+
+use super::{prelude::*, types::RequestError};
+
+pub enum Msg<T: CrudMainTrait> {
+    Loaded((time::Date, time::Date, Result<Vec<T::ReadModel>, RequestError>)),
+    SelectMode(CalendarMode),
+    Previous,
+    Next,
+    Today,
+    Read(T::ReadModel),
+    Edit(T::ReadModel),
+    DragStart(T::ReadModel),
+    DragEnd,
+    /// Drop the dragged event onto the given day.
+    Drop(time::Date),
+    Moved(Result<SaveResult<T::UpdateModel>, RequestError>),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props<T: 'static + CrudMainTrait> {
+    #[prop_or_default]
+    pub children: ChildrenRenderer<Item>,
+    #[prop_or_default]
+    pub custom_fields: CustomReadFields<T>,
+    pub api_base_url: String,
+    pub data_provider: CrudRestDataProvider<T>,
+    pub layout: CalendarLayout<T::ReadModel>,
+    #[prop_or(true)]
+    pub read_allowed: bool,
+    #[prop_or(true)]
+    pub edit_allowed: bool,
+    #[prop_or_default]
+    pub row_permissions: Option<RowPermissions<T::ReadModel>>,
+    /// Events can only be rescheduled if this is true.
+    #[prop_or(true)]
+    pub move_allowed: bool,
+    #[prop_or_default]
+    pub on_read: Callback<T::ReadModel>,
+    #[prop_or_default]
+    pub on_edit: Callback<T::ReadModel>,
+    /// Called after an event was successfully rescheduled.
+    #[prop_or_default]
+    pub on_moved: Callback<T::UpdateModel>,
+}
+
+impl<T: 'static + CrudMainTrait> Props<T> {
+    fn allows(&self, entity: &T::ReadModel, operation: EntityOperation) -> bool {
+        RowPermissions::allows(
+            self.row_permissions.as_ref(),
+            (self.read_allowed, self.edit_allowed, false),
+            entity,
+            operation,
+        )
+    }
+
+    fn movable(&self, entity: &T::ReadModel) -> bool {
+        self.move_allowed && self.allows(entity, EntityOperation::Edit)
+    }
+}
+
+/// Displays entities in a month, week or day calendar, placed by their start (and optional end) date.
+/// Only the entities overlapping the visible range are loaded.
+/// Dragging an event onto another day moves its start and end by the same amount of days.
+pub struct CrudCalendar<T: CrudMainTrait> {
+    mode: CalendarMode,
+    /// Midnight (UTC) of a day in the visible range.
+    anchor: time::OffsetDateTime,
+    events: Result<Vec<T::ReadModel>, NoData>,
+    dragged: Option<T::ReadModel>,
+    ongoing_move: bool,
+}
+
+impl<T: 'static + CrudMainTrait> CrudCalendar<T> {
+    /// First day shown and first day no longer shown.
+    fn visible_range(&self) -> (time::Date, time::Date) {
+        let anchor = self.anchor.date();
+        match self.mode {
+            CalendarMode::Month => {
+                let first = self.anchor.replace_day(1).unwrap().date();
+                let start = first - time::Duration::days(first.weekday().number_days_from_monday() as i64);
+                // Always 6 weeks, as in the date selector.
+                (start, start + time::Duration::weeks(6))
+            }
+            CalendarMode::Week => {
+                let start = anchor - time::Duration::days(anchor.weekday().number_days_from_monday() as i64);
+                (start, start + time::Duration::weeks(1))
+            }
+            CalendarMode::Day => (anchor, anchor + time::Duration::days(1)),
+        }
+    }
+
+    fn weeks(&self) -> Vec<Week> {
+        let (start, end) = self.visible_range();
+        let today = time::OffsetDateTime::now_utc().date();
+        let month = self.anchor.month();
+        let mut weeks = Vec::new();
+        let mut date = start;
+        while date < end {
+            let mut week = Week { days: Vec::with_capacity(7) };
+            while week.days.len() < 7 && date < end {
+                let in_current_month = date.month() == month;
+                let before = (date.year(), date.month() as u8) < (self.anchor.year(), month as u8);
+                week.days.push(Day {
+                    index: date.day(),
+                    display_name: date.day().to_string(),
+                    in_previous_month: !in_current_month && before,
+                    in_current_month,
+                    in_next_month: !in_current_month && !before,
+                    date_time: date.midnight().assume_utc(),
+                    disabled: false,
+                    highlighted: false,
+                    selected: false,
+                    is_now: date == today,
+                });
+                date = date + time::Duration::days(1);
+            }
+            weeks.push(week);
+        }
+        weeks
+    }
+
+    fn load(&mut self, ctx: &Context<Self>) {
+        let (start, end) = self.visible_range();
+        let layout = &ctx.props().layout;
+        let start_field = layout.start.get_name().to_owned();
+        // Conditions can not yet carry date-times themselves. The server parses these RFC 3339 strings.
+        let bound = |date: time::Date| ConditionClauseValue::String(format!("{date}T00:00:00Z"));
+        let clause = |column_name: &str, operator, date| {
+            ConditionElement::Clause(ConditionClause {
+                column_name: column_name.to_owned(),
+                operator,
+                value: bound(date),
+            })
+        };
+        let starts_before_end = clause(&start_field, crudkit_condition::Operator::Less, end);
+        let starts_after_start = clause(&start_field, crudkit_condition::Operator::GreaterOrEqual, start);
+        let condition = match &layout.end {
+            // Events overlapping the visible range. Events without an end are only shown on their start.
+            Some(end_field) => Condition::All(vec![
+                starts_before_end,
+                ConditionElement::Condition(Box::new(Condition::Any(vec![
+                    clause(end_field.get_name(), crudkit_condition::Operator::GreaterOrEqual, start),
+                    starts_after_start,
+                ]))),
+            ]),
+            None => Condition::All(vec![starts_after_start, starts_before_end]),
+        };
+        let data_provider = ctx.props().data_provider.clone();
+        let order_by = indexmap::indexmap! { ctx.props().layout.start.clone() => crudkit_shared::Order::Asc };
+        ctx.link().send_future(async move {
+            Msg::Loaded((
+                start,
+                end,
+                data_provider
+                    .read_many(ReadMany {
+                        limit: None,
+                        skip: None,
+                        order_by: Some(order_by),
+                        condition: Some(condition),
+                    })
+                    .await,
+            ))
+        });
+    }
+
+    /// The start and end of an event. Events without a usable start are not shown.
+    fn time_span(
+        ctx: &Context<Self>,
+        event: &T::ReadModel,
+    ) -> Option<(time::OffsetDateTime, time::OffsetDateTime)> {
+        let layout = &ctx.props().layout;
+        let start = as_date_time(layout.start.get_value(event))?;
+        let end = layout
+            .end
+            .as_ref()
+            .and_then(|end| as_date_time(end.get_value(event)))
+            .filter(|end| end >= &start)
+            .unwrap_or(start);
+        Some((start, end))
+    }
+
+    fn move_event(&mut self, ctx: &Context<Self>, event: T::ReadModel, to: time::Date) {
+        let (start, _end) = match Self::time_span(ctx, &event) {
+            Some(span) => span,
+            None => return,
+        };
+        let offset = to - start.date();
+        if offset.is_zero() {
+            return;
+        }
+
+        let layout = &ctx.props().layout;
+        let mut entity: T::UpdateModel = event.clone().into();
+        for field in std::iter::once(&layout.start).chain(layout.end.iter()) {
+            let name = field.get_name();
+            let update_field = match T::UpdateModel::get_all_fields()
+                .into_iter()
+                .find(|it| it.get_name() == name)
+            {
+                Some(update_field) => update_field,
+                None => {
+                    warn!("CrudCalendar: Cannot move event, as the UpdateModel has no field named '{name}'.");
+                    return;
+                }
+            };
+            if let Some(shifted) = shift(field.get_value(&event), offset) {
+                update_field.set_value(&mut entity, shifted);
+            }
+        }
+
+        let condition = <T as CrudMainTrait>::UpdateModelId::fields_iter(&entity.get_id())
+            .map(|field| (field.name().to_owned(), field.to_value()))
+            .into_all_equal_condition();
+        let data_provider = ctx.props().data_provider.clone();
+        self.ongoing_move = true;
+        ctx.link().send_future(async move {
+            Msg::Moved(
+                data_provider
+                    .update_one(UpdateOne {
+                        entity,
+                        condition: Some(condition),
+                    })
+                    .await,
+            )
+        });
+    }
+
+    fn render_day(&self, ctx: &Context<Self>, day: &Day) -> Html {
+        let date = day.date_time.date();
+        let events = match &self.events {
+            Ok(events) => events
+                .iter()
+                .filter(|event| {
+                    Self::time_span(ctx, event)
+                        .map_or(false, |(start, end)| start.date() <= date && date <= end.date())
+                })
+                .collect::<Vec<_>>(),
+            Err(_) => vec![],
+        };
+        let is_drop_target = self.dragged.is_some();
+        html! {
+            <div
+                class={classes!(
+                    "crud-calendar-day",
+                    day.in_current_month.then(|| "in-current-month"),
+                    day.is_now.then(|| "today"),
+                    is_drop_target.then(|| "drop-target"),
+                )}
+                ondragover={move |event: DragEvent| if is_drop_target { event.prevent_default() }}
+                ondrop={ctx.link().callback(move |event: DragEvent| {
+                    event.prevent_default();
+                    Msg::Drop(date)
+                })}
+            >
+                <div class={"crud-calendar-day-header"}>{day.display_name.clone()}</div>
+                <div class={"crud-calendar-day-body"}>
+                    { events.into_iter().map(|event| self.render_event(ctx, event)).collect::<Html>() }
+                </div>
+            </div>
+        }
+    }
+
+    fn render_event(&self, ctx: &Context<Self>, event: &T::ReadModel) -> Html {
+        let link = ctx.link();
+        let props = ctx.props();
+        let onclick = {
+            let event = event.clone();
+            match (
+                props.allows(&event, EntityOperation::Edit),
+                props.allows(&event, EntityOperation::Read),
+            ) {
+                (true, _) => Some(link.callback(move |_| Msg::Edit(event.clone()))),
+                (false, true) => Some(link.callback(move |_| Msg::Read(event.clone()))),
+                (false, false) => None,
+            }
+        };
+        let movable = props.movable(event);
+        let dragged = event.clone();
+        let start_time = Self::time_span(ctx, event)
+            .and_then(|(start, _end)| start.format(format_description!("[hour]:[minute]")).ok());
+        html! {
+            <div
+                class={"crud-calendar-event interactable"}
+                draggable={movable.then(|| "true")}
+                ondragstart={link.callback(move |_: DragEvent| Msg::DragStart(dragged.clone()))}
+                ondragend={link.callback(|_: DragEvent| Msg::DragEnd)}
+                {onclick}
+            >
+                if let Some(start_time) = start_time {
+                    <span class={"crud-calendar-event-time"}>{start_time}</span>
+                }
+                <span class={"crud-calendar-event-title"}>
+                    <CrudField<T::ReadModel>
+                        children={props.children.clone()}
+                        custom_fields={props.custom_fields.clone()}
+                        api_base_url={props.api_base_url.clone()}
+                        current_view={CrudSimpleView::List}
+                        field_type={props.layout.title.clone()}
+                        field_options={FieldOptions { date_time_display: DateTimeDisplay::LocalizedLocal, ..Default::default() }}
+                        entity={event.clone()}
+                        field_mode={FieldMode::Display}
+                        value_changed={|_| {}}
+                    />
+                </span>
+            </div>
+        }
+    }
+
+    fn format_range(&self) -> String {
+        let format = match self.mode {
+            CalendarMode::Month => format_description!("[month]/[year]"),
+            CalendarMode::Week | CalendarMode::Day => format_description!("[day].[month].[year]"),
+        };
+        match self.mode {
+            CalendarMode::Week => {
+                let (start, end) = self.visible_range();
+                format!(
+                    "{} - {}",
+                    start.format(format).unwrap_or_default(),
+                    (end - time::Duration::days(1)).format(format).unwrap_or_default()
+                )
+            }
+            CalendarMode::Month | CalendarMode::Day => self.anchor.format(format).unwrap_or_default(),
+        }
+    }
+}
+
+impl<T: 'static + CrudMainTrait> Component for CrudCalendar<T> {
+    type Message = Msg<T>;
+    type Properties = Props<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut calendar = Self {
+            mode: ctx.props().layout.mode,
+            anchor: time::OffsetDateTime::now_utc().date().midnight().assume_utc(),
+            events: Err(NoData::NotYetLoaded),
+            dragged: None,
+            ongoing_move: false,
+        };
+        calendar.load(ctx);
+        calendar
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.layout.mode != ctx.props().layout.mode {
+            self.mode = ctx.props().layout.mode;
+        }
+        if old_props.data_provider != ctx.props().data_provider || old_props.layout != ctx.props().layout {
+            self.load(ctx);
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Loaded((start, end, result)) => {
+                // Responses for a range no longer visible are outdated.
+                if self.visible_range() != (start, end) {
+                    return false;
+                }
+                self.events = result.map_err(NoData::FetchFailed);
+                true
+            }
+            Msg::SelectMode(mode) => {
+                self.mode = mode;
+                self.load(ctx);
+                true
+            }
+            Msg::Previous => {
+                self.anchor = match self.mode {
+                    CalendarMode::Month => CrudOffsetDatetimeDateSelector::start_of_previous_month(self.anchor),
+                    CalendarMode::Week => self.anchor - time::Duration::weeks(1),
+                    CalendarMode::Day => self.anchor - time::Duration::days(1),
+                };
+                self.load(ctx);
+                true
+            }
+            Msg::Next => {
+                self.anchor = match self.mode {
+                    CalendarMode::Month => CrudOffsetDatetimeDateSelector::start_of_next_month(self.anchor),
+                    CalendarMode::Week => self.anchor + time::Duration::weeks(1),
+                    CalendarMode::Day => self.anchor + time::Duration::days(1),
+                };
+                self.load(ctx);
+                true
+            }
+            Msg::Today => {
+                self.anchor = time::OffsetDateTime::now_utc().date().midnight().assume_utc();
+                self.load(ctx);
+                true
+            }
+            Msg::Read(event) => {
+                ctx.props().on_read.emit(event);
+                false
+            }
+            Msg::Edit(event) => {
+                ctx.props().on_edit.emit(event);
+                false
+            }
+            Msg::DragStart(event) => {
+                self.dragged = Some(event);
+                true
+            }
+            Msg::DragEnd => {
+                self.dragged = None;
+                true
+            }
+            Msg::Drop(date) => {
+                if let Some(event) = self.dragged.take() {
+                    if !self.ongoing_move {
+                        self.move_event(ctx, event, date);
+                    }
+                }
+                true
+            }
+            Msg::Moved(result) => {
+                self.ongoing_move = false;
+                match result {
+                    Ok(SaveResult::Saved(saved)) => {
+                        ctx.props().on_moved.emit(saved.entity);
+                        self.load(ctx);
+                    }
+                    Ok(SaveResult::Aborted { reason }) => {
+                        warn!("CrudCalendar: Moving the event was aborted: {reason}");
+                    }
+                    Ok(SaveResult::CriticalValidationErrors) => {
+                        warn!("CrudCalendar: Moving the event failed due to critical validation errors.");
+                    }
+                    Err(err) => {
+                        warn!("CrudCalendar: Moving the event failed: {err}");
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let weeks = self.weeks();
+        let columns = weeks.first().map_or(1, |week| week.days.len());
+        html! {
+            <div class={classes!("crud-calendar", match self.mode {
+                CalendarMode::Month => "month",
+                CalendarMode::Week => "week",
+                CalendarMode::Day => "day",
+            })}>
+                <div class={"crud-calendar-toolbar"}>
+                    <CrudBtnWrapper>
+                        <CrudBtn name={""} variant={Variant::Default} icon={Bi::ChevronLeft} onclick={ctx.link().callback(|_| Msg::Previous)} />
+                        <CrudBtn name={"Heute"} variant={Variant::Default} onclick={ctx.link().callback(|_| Msg::Today)} />
+                        <CrudBtn name={""} variant={Variant::Default} icon={Bi::ChevronRight} onclick={ctx.link().callback(|_| Msg::Next)} />
+                    </CrudBtnWrapper>
+                    <span class={"crud-calendar-range"}>{self.format_range()}</span>
+                    <CrudBtnWrapper>
+                        {
+                            [(CalendarMode::Month, "Monat"), (CalendarMode::Week, "Woche"), (CalendarMode::Day, "Tag")]
+                                .into_iter()
+                                .map(|(mode, name)| html! {
+                                    <CrudBtn
+                                        name={name}
+                                        variant={if self.mode == mode { Variant::Primary } else { Variant::Default }}
+                                        onclick={ctx.link().callback(move |_| Msg::SelectMode(mode))}
+                                    />
+                                })
+                                .collect::<Html>()
+                        }
+                    </CrudBtnWrapper>
+                </div>
+
+                if let Err(reason) = &self.events {
+                    if !matches!(reason, NoData::NotYetLoaded) {
+                        <div>{format!("Keine Daten verfügbar: {reason:?}")}</div>
+                    }
+                }
+
+                <div
+                    class={"crud-calendar-grid"}
+                    style={format!("display: grid; grid-template-columns: repeat({columns}, 1fr);")}
+                >
+                    if self.mode != CalendarMode::Day {
+                        {
+                            CrudOffsetDatetimeDateSelector::create_week_day_names(&self.anchor)
+                                .into_iter()
+                                .map(|name| html! { <div class={"crud-calendar-week-day-name"}>{name}</div> })
+                                .collect::<Html>()
+                        }
+                    }
+                    {
+                        weeks.iter()
+                            .flat_map(|week| week.days.iter())
+                            .map(|day| self.render_day(ctx, day))
+                            .collect::<Html>()
+                    }
+                </div>
+            </div>
+        }
+    }
+}
+
+fn as_date_time(value: Value) -> Option<time::OffsetDateTime> {
+    match value {
+        Value::OffsetDateTime(value) => Some(value),
+        Value::OptionalOffsetDateTime(value) => value,
+        Value::PrimitiveDateTime(value) => Some(value.assume_utc()),
+        Value::OptionalPrimitiveDateTime(value) => value.map(|value| value.assume_utc()),
+        _ => None,
+    }
+}
+
+/// Moves a date-time value by the given offset, keeping its variant.
+fn shift(value: Value, offset: time::Duration) -> Option<Value> {
+    match value {
+        Value::OffsetDateTime(value) => Some(Value::OffsetDateTime(value + offset)),
+        Value::OptionalOffsetDateTime(value) => Some(Value::OptionalOffsetDateTime(value.map(|value| value + offset))),
+        Value::PrimitiveDateTime(value) => Some(Value::PrimitiveDateTime(value + offset)),
+        Value::OptionalPrimitiveDateTime(value) => {
+            Some(Value::OptionalPrimitiveDateTime(value.map(|value| value + offset)))
+        }
+        _ => None,
+    }
+}
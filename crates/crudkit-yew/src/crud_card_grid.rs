@@ -0,0 +1,313 @@
+use crudkit_shared::Order;
+use std::rc::Rc;
+use yew::{html::ChildrenRenderer, prelude::*};
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use crate::{
+    crud_instance::Item,
+    crud_table::RowPermissions,
+    types::{custom_field::CustomFields, permissions::EntityOperation},
+};
+
+use super::prelude::*;
+
+/// Cards are at least this wide (in pixels). As many cards as fit are placed next to each other.
+const MIN_CARD_WIDTH: u32 = 260;
+
+pub enum Msg<T: CrudDataTrait> {
+    Select(T, bool),
+    SelectAll(bool),
+    OrderBy(T::Field),
+    Read(T),
+    Edit(T),
+    Delete(T),
+    ActionTriggered((Rc<Box<dyn CrudActionTrait>>, T)),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props<T>
+where
+    T: CrudDataTrait,
+{
+    pub children: ChildrenRenderer<Item>,
+    pub custom_fields: CustomFields<T>,
+    pub api_base_url: String,
+    pub data: Option<Rc<Vec<T>>>,
+    pub no_data: Option<(NoData, time::OffsetDateTime)>,
+    pub layout: CardLayout<T>,
+    /// The columns the table would show. Used to label fields and to offer ordering.
+    pub headers: Vec<(T::Field, HeaderOptions, Option<Order>)>,
+    pub on_order_by: Callback<(T::Field, OrderByUpdateOptions)>,
+    pub read_allowed: bool,
+    pub edit_allowed: bool,
+    pub delete_allowed: bool,
+    #[prop_or_default]
+    pub row_permissions: Option<RowPermissions<T>>,
+    pub selected: Vec<T>,
+    pub on_selection: Callback<Vec<T>>,
+    pub on_read: Callback<T>,
+    pub on_edit: Callback<T>,
+    pub on_delete: Callback<T>,
+    pub additional_item_actions: Vec<Rc<Box<dyn CrudActionTrait>>>,
+    pub on_additional_item_action: Callback<(Rc<Box<dyn CrudActionTrait>>, T)>,
+}
+
+impl<T: 'static + CrudDataTrait> Props<T> {
+    /// Whether the given operation may be performed on the entity of a card.
+    pub fn allows(&self, entity: &T, operation: EntityOperation) -> bool {
+        RowPermissions::allows(
+            self.row_permissions.as_ref(),
+            (self.read_allowed, self.edit_allowed, self.delete_allowed),
+            entity,
+            operation,
+        )
+    }
+
+    fn display_name(&self, field: &T::Field) -> String {
+        self.headers
+            .iter()
+            .find(|(it, _options, _order)| it == field)
+            .map(|(_field, options, _order)| options.display_name.clone())
+            .unwrap_or_else(|| field.get_name().to_owned())
+    }
+
+    fn field_options(&self, field: &T::Field) -> FieldOptions {
+        self.headers
+            .iter()
+            .find(|(it, _options, _order)| it == field)
+            .map_or_else(FieldOptions::default, |(_field, options, _order)| FieldOptions {
+                date_time_display: options.date_time_display,
+                formatter: options.formatter.clone(),
+                ..Default::default()
+            })
+    }
+}
+
+/// Presents entities as cards instead of table rows. Intended for narrow screens.
+pub struct CrudCardGrid<T> {
+    selected: Vec<T>,
+}
+
+impl<T: 'static + CrudDataTrait> CrudCardGrid<T> {
+    fn render_field(&self, ctx: &Context<Self>, entity: &T, field: &T::Field) -> Html {
+        html! {
+            <CrudField<T>
+                children={ctx.props().children.clone()}
+                custom_fields={ctx.props().custom_fields.clone()}
+                api_base_url={ctx.props().api_base_url.clone()}
+                current_view={CrudSimpleView::List}
+                field_type={field.clone()}
+                field_options={ctx.props().field_options(field)}
+                entity={entity.clone()}
+                field_mode={FieldMode::Display}
+                value_changed={|_| {}}
+            />
+        }
+    }
+
+    fn render_card(&self, ctx: &Context<Self>, entity: &T) -> Html {
+        let link = ctx.link();
+        let props = ctx.props();
+        let read_allowed = props.allows(entity, EntityOperation::Read);
+        let edit_allowed = props.allows(entity, EntityOperation::Edit);
+        let delete_allowed = props.allows(entity, EntityOperation::Delete);
+        let selected = self.selected.contains(entity);
+
+        let onclick = {
+            let entity = entity.clone();
+            match (edit_allowed, read_allowed) {
+                (true, _) => Some(link.callback(move |_| Msg::Edit(entity.clone()))),
+                (false, true) => Some(link.callback(move |_| Msg::Read(entity.clone()))),
+                (false, false) => None,
+            }
+        };
+        let select_entity = entity.clone();
+
+        html! {
+            <div class={classes!("crud-card", "interactable", selected.then(|| "selected"))} {onclick}>
+                if let Some(image) = &props.layout.image {
+                    <div class={"crud-card-image"}>
+                        { self.render_field(ctx, entity, image) }
+                    </div>
+                }
+                <div class={"crud-card-header"}>
+                    <div onclick={|it: MouseEvent| { it.stop_propagation() }}>
+                        <CrudCheckbox
+                            state={selected}
+                            on_toggle={link.callback(move |state| Msg::Select(select_entity.clone(), state))}
+                        />
+                    </div>
+                    <div class={"crud-card-title"}>
+                        { self.render_field(ctx, entity, &props.layout.title) }
+                    </div>
+                </div>
+                <div class={"crud-card-body"}>
+                    {
+                        props.layout.subtitles.iter().map(|field| html! {
+                            <div class={"crud-card-subtitle"}>
+                                <span class={"crud-card-subtitle-label"}>{props.display_name(field)}</span>
+                                { self.render_field(ctx, entity, field) }
+                            </div>
+                        }).collect::<Html>()
+                    }
+                </div>
+                <div class={"crud-card-footer action-icons"} onclick={|it: MouseEvent| { it.stop_propagation() }}>
+                    if read_allowed {
+                        <div class={"action-icon"} onclick={link.callback({ let entity = entity.clone(); move |_| Msg::Read(entity.clone()) })}>
+                            <CrudIcon variant={Bi::Eye}/>
+                        </div>
+                    }
+                    if edit_allowed {
+                        <div class={"action-icon"} onclick={link.callback({ let entity = entity.clone(); move |_| Msg::Edit(entity.clone()) })}>
+                            <CrudIcon variant={Bi::Pencil}/>
+                        </div>
+                    }
+                    if delete_allowed {
+                        <div class={"action-icon"} onclick={link.callback({ let entity = entity.clone(); move |_| Msg::Delete(entity.clone()) })}>
+                            <CrudIcon variant={Bi::Trash}/>
+                        </div>
+                    }
+                    {
+                        props.additional_item_actions.iter().map(|action| {
+                            let cloned_action = action.clone();
+                            let cloned_entity = entity.clone();
+                            html! {
+                                <div
+                                    class={"action-icon"}
+                                    onclick={link.callback(move |_| Msg::ActionTriggered((cloned_action.clone(), cloned_entity.clone())))}>
+                                    <CrudIcon variant={action.get_icon().unwrap_or(Bi::Question)}/>
+                                </div>
+                            }
+                        }).collect::<Html>()
+                    }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl<T> Component for CrudCardGrid<T>
+where
+    T: 'static + CrudDataTrait,
+{
+    type Message = Msg<T>;
+    type Properties = Props<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            selected: ctx.props().selected.clone(),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.selected != ctx.props().selected {
+            self.selected = ctx.props().selected.clone();
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Select(entity, state) => {
+                let pos = self.selected.iter().position(|it| it == &entity);
+                match (pos, state) {
+                    (None, true) => self.selected.push(entity),
+                    (Some(pos), false) => {
+                        self.selected.remove(pos);
+                    }
+                    _ => {}
+                }
+                ctx.props().on_selection.emit(self.selected.clone());
+                true
+            }
+            Msg::SelectAll(state) => {
+                self.selected.clear();
+                if let (true, Some(data)) = (state, &ctx.props().data) {
+                    self.selected.extend(data.iter().cloned());
+                }
+                ctx.props().on_selection.emit(self.selected.clone());
+                true
+            }
+            Msg::OrderBy(field) => {
+                ctx.props()
+                    .on_order_by
+                    .emit((field, OrderByUpdateOptions { append: false }));
+                false
+            }
+            Msg::Read(entity) => {
+                ctx.props().on_read.emit(entity);
+                false
+            }
+            Msg::Edit(entity) => {
+                ctx.props().on_edit.emit(entity);
+                false
+            }
+            Msg::Delete(entity) => {
+                ctx.props().on_delete.emit(entity);
+                false
+            }
+            Msg::ActionTriggered(action) => {
+                ctx.props().on_additional_item_action.emit(action);
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let data_count = ctx.props().data.as_ref().map_or(0, |data| data.len());
+        html! {
+            <div class={"crud-card-grid-wrapper"}>
+                <div class={"crud-card-grid-toolbar"}>
+                    <CrudCheckbox
+                        state={data_count > 0 && self.selected.len() == data_count}
+                        on_toggle={ctx.link().callback(Msg::SelectAll)}
+                    />
+                    <span class={"crud-card-grid-order-label"}>{"Sortieren nach:"}</span>
+                    {
+                        ctx.props().headers.iter()
+                            .filter(|(_field, options, _order)| options.ordering_allowed)
+                            .map(|(field, options, order)| {
+                                let field = field.clone();
+                                html! {
+                                    <span
+                                        class={classes!("crud-order-by-trigger", order.is_some().then(|| "crud-column-ordered"))}
+                                        onclick={ctx.link().callback(move |_| Msg::OrderBy(field.clone()))}
+                                    >
+                                        {options.display_name.clone()}
+                                        <span class={classes!("crud-order-by-sign", order.is_some().then(|| "active"))}>
+                                            <CrudSafeHtml html={
+                                                match order {
+                                                    Some(Order::Desc) => "&darr;",
+                                                    Some(Order::Asc) | None => "&uarr;",
+                                                }
+                                            } />
+                                        </span>
+                                    </span>
+                                }
+                            })
+                            .collect::<Html>()
+                    }
+                </div>
+                <div
+                    class={"crud-card-grid"}
+                    style={format!("display: grid; grid-template-columns: repeat(auto-fill, minmax({MIN_CARD_WIDTH}px, 1fr)); gap: 1em;")}
+                >
+                    {
+                        match (&ctx.props().data, &ctx.props().no_data) {
+                            (Some(data), _) if data.is_empty() => html! {
+                                <div class={"no-data"}>{"Keine Daten"}</div>
+                            },
+                            (Some(data), _) => data.iter().map(|entity| self.render_card(ctx, entity)).collect::<Html>(),
+                            (None, Some((reason, since))) => if (time::OffsetDateTime::now_utc() - *since).whole_seconds() > 1 {
+                                html! { <div>{format!("Keine Daten verfügbar: {reason:?}")}</div> }
+                            } else {
+                                html! {}
+                            },
+                            (None, None) => html! { "Component misconfigured: Either pass some data or an error, not both." },
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
+}
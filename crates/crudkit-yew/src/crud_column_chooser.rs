@@ -0,0 +1,189 @@
+use yew::prelude::*;
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use super::prelude::*;
+
+pub enum Msg<T: CrudDataTrait> {
+    Toggle(T::Field, bool),
+    DragStart(usize),
+    DragEnd,
+    Drop(usize),
+    Reset,
+    Close,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props<T: CrudDataTrait> {
+    /// The currently visible columns, in the order in which they are displayed.
+    /// Excluded fields contained in here are not listed, but are kept when the columns are changed.
+    pub headers: Vec<(T::Field, HeaderOptions)>,
+    /// The columns as configured by the developer. Used to restore the options of a column which gets shown again.
+    pub default_headers: Vec<(T::Field, HeaderOptions)>,
+    /// Fields which must not be offered at all, for example because the user is not permitted to see them.
+    #[prop_or_default]
+    pub excluded: Vec<T::Field>,
+    pub on_change: Callback<Vec<(T::Field, HeaderOptions)>>,
+    pub on_reset: Callback<()>,
+    pub on_close: Callback<()>,
+}
+
+/// Lets the user choose which fields of a model are shown as columns and in which order.
+/// Hidden fields are listed after the visible ones. Visible fields can be reordered using drag and drop.
+pub struct CrudColumnChooser<T: CrudDataTrait> {
+    /// Index (into `headers`) of the column currently being dragged.
+    dragged: Option<usize>,
+    phantom_data: std::marker::PhantomData<T>,
+}
+
+impl<T: 'static + CrudDataTrait> CrudColumnChooser<T> {
+    fn options_for(ctx: &Context<Self>, field: &T::Field) -> HeaderOptions {
+        ctx.props()
+            .default_headers
+            .iter()
+            .find(|(it, _options)| it == field)
+            .map(|(_field, options)| options.clone())
+            .unwrap_or_else(|| HeaderOptions {
+                display_name: field.get_name().to_owned(),
+                ..Default::default()
+            })
+    }
+}
+
+impl<T: 'static + CrudDataTrait> Component for CrudColumnChooser<T> {
+    type Message = Msg<T>;
+    type Properties = Props<T>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            dragged: None,
+            phantom_data: std::marker::PhantomData {},
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Toggle(field, visible) => {
+                let mut headers = ctx.props().headers.clone();
+                let pos = headers.iter().position(|(it, _options)| it == &field);
+                match (pos, visible) {
+                    (None, true) => {
+                        let options = Self::options_for(ctx, &field);
+                        headers.push((field, options));
+                    }
+                    (Some(pos), false) => {
+                        headers.remove(pos);
+                    }
+                    _ => return false,
+                }
+                ctx.props().on_change.emit(headers);
+                false
+            }
+            Msg::DragStart(index) => {
+                self.dragged = Some(index);
+                true
+            }
+            Msg::DragEnd => {
+                self.dragged = None;
+                true
+            }
+            Msg::Drop(target) => {
+                if let Some(source) = self.dragged.take() {
+                    let mut headers = ctx.props().headers.clone();
+                    if source != target && source < headers.len() && target < headers.len() {
+                        let header = headers.remove(source);
+                        headers.insert(target, header);
+                        ctx.props().on_change.emit(headers);
+                    }
+                }
+                true
+            }
+            Msg::Reset => {
+                ctx.props().on_reset.emit(());
+                false
+            }
+            Msg::Close => {
+                ctx.props().on_close.emit(());
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let headers = &ctx.props().headers;
+        let hidden = T::get_all_fields()
+            .into_iter()
+            .filter(|field| !headers.iter().any(|(it, _options)| it == field))
+            .filter(|field| !ctx.props().excluded.contains(field))
+            .collect::<Vec<T::Field>>();
+
+        html! {
+            <div class={"crud-modal crud-column-chooser"}>
+                <div class={"crud-modal-header"}>
+                    <div class={"crud-modal-title"}>
+                        {"Spalten"}
+                    </div>
+                </div>
+
+                <div class={"crud-modal-body"}>
+                    <div class={"crud-column-chooser-list"}>
+                        {
+                            headers.iter().enumerate().filter(|(_index, (field, _options))| !ctx.props().excluded.contains(field)).map(|(index, (field, options))| {
+                                let toggled_field = field.clone();
+                                html! {
+                                    <div
+                                        key={field.get_name()}
+                                        class={classes!("crud-column-chooser-entry", "visible", (self.dragged == Some(index)).then_some("dragged"))}
+                                        draggable={"true"}
+                                        ondragstart={ctx.link().callback(move |_: DragEvent| Msg::DragStart(index))}
+                                        ondragend={ctx.link().callback(|_: DragEvent| Msg::DragEnd)}
+                                        ondragover={|event: DragEvent| event.prevent_default()}
+                                        ondrop={ctx.link().callback(move |event: DragEvent| {
+                                            event.prevent_default();
+                                            Msg::Drop(index)
+                                        })}
+                                    >
+                                        <CrudIcon variant={Bi::GripVertical}/>
+                                        <CrudCheckbox
+                                            state={true}
+                                            on_toggle={ctx.link().callback(move |state| Msg::Toggle(toggled_field.clone(), state))}
+                                        />
+                                        <span class={"crud-column-chooser-name"}>{options.display_name.clone()}</span>
+                                    </div>
+                                }
+                            }).collect::<Html>()
+                        }
+                        {
+                            hidden.into_iter().map(|field| {
+                                let name = Self::options_for(ctx, &field).display_name;
+                                html! {
+                                    <div key={field.get_name()} class={"crud-column-chooser-entry hidden"}>
+                                        <CrudCheckbox
+                                            state={false}
+                                            on_toggle={ctx.link().callback(move |state| Msg::Toggle(field.clone(), state))}
+                                        />
+                                        <span class={"crud-column-chooser-name"}>{name}</span>
+                                    </div>
+                                }
+                            }).collect::<Html>()
+                        }
+                    </div>
+                </div>
+
+                <div class={"crud-modal-footer"}>
+                    <div class={"crud-row"}>
+                        <div class={"crud-col"}>
+                            <CrudBtnWrapper>
+                                <CrudBtn name={"Zurücksetzen"} variant={Variant::Default} icon={Bi::ArrowCounterclockwise} onclick={ctx.link().callback(|_| Msg::Reset)}/>
+                            </CrudBtnWrapper>
+                        </div>
+                        <div class={"crud-col crud-col-flex-end"}>
+                            <CrudBtnWrapper>
+                                <CrudBtn name={"Schließen"} variant={Variant::Primary} onclick={ctx.link().callback(|_| Msg::Close)}/>
+                            </CrudBtnWrapper>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
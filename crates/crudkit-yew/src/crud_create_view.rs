@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
+use gloo::timers::callback::Timeout;
 use tracing::{error, info};
 use yew::{
     html::{ChildrenRenderer, Scope},
     prelude::*,
 };
+use yew_bootstrap_icons::v1_10_3::Bi;
+use yewdux::prelude::Dispatch;
 
 use crudkit_id::SerializableId;
 use crudkit_shared::{SaveResult, Saved};
@@ -12,13 +15,23 @@ use crudkit_shared::{SaveResult, Saved};
 use crate::{
     crud_instance::Item,
     services::crud_rest_data_provider::{CreateOne, CrudRestDataProvider},
-    types::custom_field::{CustomCreateFields, CustomUpdateFields},
+    stores::drafts::{Draft, DraftsStore},
+    types::{
+        computed_field::{self, ComputedCreateFields},
+        custom_field::{CustomCreateFields, CustomUpdateFields},
+        field_condition,
+        form_state::FormState,
+    },
 };
 
 use super::{prelude::*, types::RequestError};
 
+const MILLIS_UNTIL_DRAFT_IS_SAVED: u32 = 1000;
+
 pub enum Msg<T: CrudMainTrait> {
     Back,
+    BackCanceled,
+    BackApproved,
     Save,
     SaveAndReturn,
     SaveAndNew,
@@ -42,6 +55,11 @@ pub enum Msg<T: CrudMainTrait> {
             Box<dyn FnOnce(Value)>,
         ),
     ),
+    Undo,
+    Redo,
+    SaveDraft,
+    RestoreDraft,
+    DiscardDraft,
 }
 
 pub enum Then {
@@ -56,11 +74,13 @@ pub struct Props<T: 'static + CrudMainTrait> {
     pub children: ChildrenRenderer<Item>,
     pub custom_create_fields: CustomCreateFields<T>,
     pub custom_update_fields: CustomUpdateFields<T>,
+    pub computed_create_fields: ComputedCreateFields<T>,
     pub data_provider: CrudRestDataProvider<T>,
     /// Required because when creating the initial CreateModel, we have to set the "parent id" field of that model to the given id.
     /// TODO: Only a subset of the parent id might be required to for matching. Consider a CreateModel#initialize_with_parent_id(ParentId)...
     pub parent_id: Option<SerializableId>,
     pub config: CrudInstanceConfig<T>,
+    pub permissions: PermissionPolicy<T>,
     pub list_view_available: bool,
     pub on_list_view: Callback<()>,
     // TODO: consolidate these into one "on_entity_creation_attempt" with type Result<CreateResult<T::UpdateModel>, SomeErrorType>?
@@ -72,6 +92,9 @@ pub struct Props<T: 'static + CrudMainTrait> {
     pub on_entity_not_created_critical_errors: Callback<()>,
     pub on_entity_creation_failed: Callback<RequestError>,
     pub on_tab_selected: Callback<Label>,
+    pub on_dirty_changed: Callback<bool>,
+    /// Name of the surrounding instance. Identifies the drafts of this view.
+    pub instance_name: String,
 }
 
 /// The create view shows the form with which the user can CREATE a new entity of the given resource.
@@ -81,17 +104,100 @@ pub struct Props<T: 'static + CrudMainTrait> {
 /// These fields are then also used for creation, requiring this component to be able to work with the create and the update model!
 /// This component decides on its own, depending on the instance configuration, which fields to display.
 pub struct CrudCreateView<T: CrudMainTrait> {
-    initial_data: Option<T::CreateModel>,
     input: T::CreateModel,
     ongoing_save: bool,
 
-    // TODO: input_dirty like in EditView? Why not here?
+    /// Tracks whether `input` was changed since the form was opened or reset.
+    form_state: FormState<T::CreateModel>,
+    user_wants_to_leave: bool,
+
+    drafts_dispatch: Dispatch<DraftsStore<T>>,
+    /// Unsaved input from an earlier visit, which the user may restore.
+    draft: Option<Draft<T::CreateModel>>,
+    /// Saves a draft of the input shortly after the user stopped changing it.
+    draft_timeout: Option<Timeout>,
+
     /// The input is erroneous if at least one field is contained in this list.
     create_input_errors: HashMap<<T::CreateModel as CrudDataTrait>::Field, String>,
 }
 
 impl<T: 'static + CrudMainTrait> CrudCreateView<T> {
+    /// Sets the value of a field, keeping everything depending on the input up to date.
+    /// Returns whether the view must be rendered again.
+    fn set_value(
+        &mut self,
+        ctx: &Context<Self>,
+        field: <T::CreateModel as CrudDataTrait>::Field,
+        value: Value,
+    ) -> bool {
+        let validation = field.validate(&value);
+        let elements = Self::create_elements(ctx);
+        let conditions = field_condition::evaluate_conditions(elements, &self.input);
+        let value_changed = !field.get_value(&self.input).is_same_as(&value);
+        field.set_value(&mut self.input, value);
+        let computed_changed =
+            computed_field::apply(&ctx.props().computed_create_fields, &mut self.input);
+        self.form_state.update(&self.input);
+        // The current input supersedes an older draft.
+        self.draft = None;
+        self.schedule_draft(ctx);
+        let conditions_changed = conditions != field_condition::evaluate_conditions(elements, &self.input);
+        if conditions_changed {
+            let visible = field_condition::visible_fields(elements, &self.input);
+            self.create_input_errors.retain(|field, _| visible.contains(field));
+        }
+        // Rerender if the value or a computed value changed, e.g. when an optional field was cleared,
+        // or if the error shown for this field or the visibility of fields changed.
+        let error_changed = match validation {
+            Ok(()) => self.create_input_errors.remove(&field).is_some(),
+            Err(err) => self.create_input_errors.insert(field, err.clone()) != Some(err),
+        };
+        value_changed || computed_changed || error_changed || conditions_changed
+    }
+
+    fn schedule_draft(&mut self, ctx: &Context<Self>) {
+        let link = ctx.link().clone();
+        self.draft_timeout = Some(Timeout::new(MILLIS_UNTIL_DRAFT_IS_SAVED, move || {
+            link.send_message(Msg::SaveDraft)
+        }));
+    }
+
+    fn discard_draft(&mut self, ctx: &Context<Self>) {
+        self.draft = None;
+        self.draft_timeout = None;
+        let name = ctx.props().instance_name.clone();
+        self.drafts_dispatch
+            .reduce_mut(|state| state.remove_create_draft(&name));
+    }
+
+    fn is_save_disabled(&self) -> bool {
+        self.ongoing_save || !self.create_input_errors.is_empty()
+    }
+
+    fn create_elements(ctx: &Context<Self>) -> &[Elem<T::CreateModel>] {
+        match &ctx.props().config.create_elements {
+            CreateElements::None => &[],
+            CreateElements::Custom(create_elements) => create_elements,
+        }
+    }
+
+    /// Runs the validators of all shown fields, as fields the user did not touch were not validated yet.
+    /// Errors of hidden fields are dropped. Returns whether the input is free of errors.
+    fn validate_input(&mut self, ctx: &Context<Self>) -> bool {
+        let visible = field_condition::visible_fields(Self::create_elements(ctx), &self.input);
+        self.create_input_errors.retain(|field, _| visible.contains(field));
+        for field in visible {
+            if let Err(err) = field.validate(&field.get_value(&self.input)) {
+                self.create_input_errors.insert(field, err);
+            }
+        }
+        self.create_input_errors.is_empty()
+    }
+
     fn create_entity(&mut self, ctx: &Context<Self>, then: Then) {
+        if !self.validate_input(ctx) {
+            return;
+        }
         let create_model = self.input.clone();
         let data_provider = ctx.props().data_provider.clone();
         self.ongoing_save = true;
@@ -109,6 +215,7 @@ impl<T: 'static + CrudMainTrait> CrudCreateView<T> {
 
     fn reset(&mut self, ctx: &Context<Self>) {
         self.input = default_create_model(ctx);
+        self.form_state.reset(self.input.clone());
     }
 }
 
@@ -131,6 +238,7 @@ fn default_create_model<T: CrudMainTrait + 'static>(
             error!("CrudInstance is configured to be a nested instance but no parent id was passed down!");
         }
     }
+    computed_field::apply(&ctx.props().computed_create_fields, &mut entity);
     entity
 }
 
@@ -141,10 +249,21 @@ impl<T: 'static + CrudMainTrait> Component for CrudCreateView<T> {
     fn create(ctx: &Context<Self>) -> Self {
         ctx.props().on_link.emit(Some(ctx.link().clone()));
         let create_model = default_create_model(ctx);
+        let mut form_state = FormState::new(ctx.props().on_dirty_changed.clone());
+        form_state.reset(create_model.clone());
+        let drafts_dispatch = Dispatch::<DraftsStore<T>>::new();
+        let draft = drafts_dispatch
+            .get()
+            .get_create_draft(&ctx.props().instance_name)
+            .filter(|draft| draft.input != create_model);
         Self {
-            initial_data: Some(create_model.clone()),
             input: create_model,
             ongoing_save: false,
+            form_state,
+            user_wants_to_leave: false,
+            drafts_dispatch,
+            draft,
+            draft_timeout: None,
             create_input_errors: HashMap::new(),
         }
     }
@@ -156,20 +275,39 @@ impl<T: 'static + CrudMainTrait> Component for CrudCreateView<T> {
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::Back => {
+                self.user_wants_to_leave = true;
+                match self.form_state.is_dirty() {
+                    true => {
+                        // Waiting for the modal to be resolved!
+                        true
+                    }
+                    false => {
+                        ctx.props().on_list_view.emit(());
+                        false
+                    }
+                }
+            }
+            Msg::BackCanceled => {
+                self.user_wants_to_leave = false;
+                true
+            }
+            Msg::BackApproved => {
+                self.form_state.discard();
+                self.discard_draft(ctx);
                 ctx.props().on_list_view.emit(());
                 false
             }
             Msg::Save => {
                 self.create_entity(ctx, Then::DoNothing);
-                false
+                true
             }
             Msg::SaveAndReturn => {
                 self.create_entity(ctx, Then::OpenListView);
-                false
+                true
             }
             Msg::SaveAndNew => {
                 self.create_entity(ctx, Then::Reset);
-                false
+                true
             }
             Msg::TabSelected(label) => {
                 ctx.props().on_tab_selected.emit(label);
@@ -177,35 +315,42 @@ impl<T: 'static + CrudMainTrait> Component for CrudCreateView<T> {
             }
             Msg::CreateModelFieldChanged((field, result)) => match result {
                 Ok(value) => {
-                    field.set_value(&mut self.input, value);
-                    self.create_input_errors.remove(&field);
-                    false
+                    let history = (self.form_state.can_undo(), self.form_state.can_redo());
+                    self.form_state.record(field.clone(), field.get_value(&self.input), value.clone());
+                    let history_changed =
+                        history != (self.form_state.can_undo(), self.form_state.can_redo());
+                    self.set_value(ctx, field, value) || history_changed
                 }
                 Err(err) => {
                     self.create_input_errors.insert(field, err);
-                    false
+                    true
                 }
             },
             Msg::CreatedEntity(result, then) => {
                 self.ongoing_save = false;
                 match result {
                     Ok(create_result) => match create_result {
-                        SaveResult::Saved(created) => match then {
-                            Then::DoNothing => {
-                                ctx.props().on_entity_created.emit((created, None));
-                            }
-                            Then::OpenListView => {
-                                ctx.props()
-                                    .on_entity_created
-                                    .emit((created, Some(CrudView::List)));
+                        SaveResult::Saved(created) => {
+                            // The input is saved. Leaving the form is fine now.
+                            self.form_state.reset(self.input.clone());
+                            self.discard_draft(ctx);
+                            match then {
+                                Then::DoNothing => {
+                                    ctx.props().on_entity_created.emit((created, None));
+                                }
+                                Then::OpenListView => {
+                                    ctx.props()
+                                        .on_entity_created
+                                        .emit((created, Some(CrudView::List)));
+                                }
+                                Then::Reset => {
+                                    ctx.props()
+                                        .on_entity_created
+                                        .emit((created, Some(CrudView::Create)));
+                                    self.reset(ctx);
+                                }
                             }
-                            Then::Reset => {
-                                ctx.props()
-                                    .on_entity_created
-                                    .emit((created, Some(CrudView::Create)));
-                                self.reset(ctx);
-                            }
-                        },
+                        }
                         SaveResult::Aborted { reason } => {
                             ctx.props().on_entity_creation_aborted.emit(reason);
                         }
@@ -225,19 +370,59 @@ impl<T: 'static + CrudMainTrait> Component for CrudCreateView<T> {
                 receiver(field.get_value(&self.input));
                 false
             }
+            Msg::SaveDraft => {
+                self.draft_timeout = None;
+                match self.form_state.is_dirty() {
+                    true => {
+                        let name = ctx.props().instance_name.clone();
+                        let draft = Draft::new(self.input.clone());
+                        self.drafts_dispatch
+                            .reduce_mut(|state| state.save_create_draft(name, draft));
+                    }
+                    false => self.discard_draft(ctx),
+                }
+                false
+            }
+            Msg::RestoreDraft => {
+                if let Some(draft) = self.draft.take() {
+                    self.input = draft.input;
+                    self.form_state.update(&self.input);
+                }
+                true
+            }
+            Msg::DiscardDraft => {
+                self.discard_draft(ctx);
+                true
+            }
+            Msg::Undo => match self.form_state.undo() {
+                Some((field, value)) => {
+                    self.set_value(ctx, field, value);
+                    true
+                }
+                None => false,
+            },
+            Msg::Redo => match self.form_state.redo() {
+                Some((field, value)) => {
+                    self.set_value(ctx, field, value);
+                    true
+                }
+                None => false,
+            },
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         html! {
-            <>
+            <div onkeydown={self.form_state.history_shortcuts(ctx.link().callback(|_| Msg::Undo), ctx.link().callback(|_| Msg::Redo))}>
             <div class={"crud-row crud-nav"}>
                 <div class={"crud-col"}>
                     <CrudBtnWrapper>
-                        <CrudBtn name={"Speichern"} variant={Variant::Primary} disabled={self.ongoing_save} onclick={ctx.link().callback(|_| Msg::Save)}>
-                            <CrudBtn name={"Speichern und zurück"} variant={Variant::Primary} disabled={self.ongoing_save} onclick={ctx.link().callback(|_| Msg::SaveAndReturn)} />
-                            <CrudBtn name={"Speichern und neu"} variant={Variant::Primary} disabled={self.ongoing_save} onclick={ctx.link().callback(|_| Msg::SaveAndNew)} />
+                        <CrudBtn name={"Speichern"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::Save)}>
+                            <CrudBtn name={"Speichern und zurück"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::SaveAndReturn)} />
+                            <CrudBtn name={"Speichern und neu"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::SaveAndNew)} />
                         </CrudBtn>
+                        <CrudBtn name={"Rückgängig"} variant={Variant::Default} icon={Bi::ArrowCounterclockwise} disabled={!self.form_state.can_undo()} onclick={ctx.link().callback(|_| Msg::Undo)} />
+                        <CrudBtn name={"Wiederholen"} variant={Variant::Default} icon={Bi::ArrowClockwise} disabled={!self.form_state.can_redo()} onclick={ctx.link().callback(|_| Msg::Redo)} />
                     </CrudBtnWrapper>
                 </div>
 
@@ -251,6 +436,15 @@ impl<T: 'static + CrudMainTrait> Component for CrudCreateView<T> {
                     </CrudBtnWrapper>
                 </div>
             </div>
+            if let Some(draft) = &self.draft {
+                <CrudAlert variant={crate::crud_alert::Variant::Info}>
+                    {format!("Es gibt ungespeicherte Eingaben vom {}. Möchtest du sie wiederherstellen?", draft.saved_at_localized())}
+                    <CrudBtnWrapper>
+                        <CrudBtn name={"Wiederherstellen"} variant={Variant::Primary} onclick={ctx.link().callback(|_| Msg::RestoreDraft)} />
+                        <CrudBtn name={"Verwerfen"} variant={Variant::Default} onclick={ctx.link().callback(|_| Msg::DiscardDraft)} />
+                    </CrudBtnWrapper>
+                </CrudAlert>
+            }
             {
                 match &ctx.props().config.create_elements {
                     CreateElements::None => html! {},
@@ -263,28 +457,30 @@ impl<T: 'static + CrudMainTrait> Component for CrudCreateView<T> {
                                 Item::Select(select) => select.props.for_model == crate::crud_reset_field::Model::Create,
                             }).collect::<Vec<Item>>())}
                             custom_fields={ctx.props().custom_create_fields.clone()}
-                            elements={create_elements.clone()}
-                            entity={self.initial_data.clone()}
+                            elements={computed_field::disable_computed(
+                                &ctx.props().computed_create_fields,
+                                ctx.props().permissions.restrict_elements(&CrudSimpleView::Create, create_elements),
+                            )}
+                            entity={Some(self.input.clone())}
                             mode={FieldMode::Editable}
                             current_view={CrudSimpleView::Create}
                             value_changed={ctx.link().callback(Msg::CreateModelFieldChanged)}
+                            errors={self.create_input_errors.clone()}
                             active_tab={ctx.props().config.active_tab.clone()}
                             on_tab_selection={ctx.link().callback(|label| Msg::TabSelected(label))}
                         />
                     },
                 }
             }
-            </>
-        }
-    }
-
-    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
-        if first_render {
-            // The initial_data field is used to declare the initial field states.
-            // But: We only want to pass down that data once!
-            // After we did that, no rerender of this component must overwrite the users input data.
-            // Leaving it to Some(*) would erase the user input on the second render, as this data is passed as the 'entity' to the CrudFields component.
-            self.initial_data = None;
+            if self.user_wants_to_leave {
+                <CrudModal>
+                    <CrudLeaveModal
+                        on_cancel={ctx.link().callback(|_| Msg::BackCanceled)}
+                        on_leave={ctx.link().callback(|_| Msg::BackApproved)}
+                    />
+                </CrudModal>
+            }
+            </div>
         }
     }
 }
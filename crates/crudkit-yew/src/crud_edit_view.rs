@@ -5,25 +5,33 @@ use crudkit_id::Id;
 use crudkit_id::IdField;
 use crudkit_shared::{SaveResult, Saved};
 
-use gloo::timers::callback::Interval;
+use gloo::timers::callback::{Interval, Timeout};
 use tracing::{info, warn};
 use yew::{
     html::{ChildrenRenderer, Scope},
     prelude::*,
 };
+use yew_bootstrap_icons::v1_10_3::Bi;
+use yewdux::prelude::Dispatch;
 
 use crate::{
     crud_action::EntityModalGeneration,
     crud_instance::Item,
+    crud_review_changes_modal::{field_changes, FieldChange},
     services::crud_rest_data_provider::{CrudRestDataProvider, ReadOne, UpdateOne},
-    types::custom_field::CustomUpdateFields,
+    stores::drafts::{Draft, DraftsStore},
+    types::{
+        computed_field,
+        custom_field::CustomUpdateFields,
+        field_condition,
+        form_state::FormState,
+    },
 };
 
 use super::{prelude::*, types::RequestError};
 
 const MILLIS_UNTIL_ERROR_IS_SHOWN: u32 = 1000;
-
-// TODO: CrudEditView tracks changes, but CrudCreateView does not. Consolidate this logic into a shared component.
+const MILLIS_UNTIL_DRAFT_IS_SAVED: u32 = 1000;
 
 pub enum Msg<T: CrudMainTrait> {
     Back,
@@ -35,6 +43,9 @@ pub enum Msg<T: CrudMainTrait> {
     Save,
     SaveAndReturn,
     SaveAndNew,
+    ReviewCanceled,
+    ReviewConfirmed,
+    RevertField(<T::UpdateModel as CrudDataTrait>::Field),
     Delete,
     TabSelected(Label),
     ValueChanged(
@@ -69,6 +80,11 @@ pub enum Msg<T: CrudMainTrait> {
         result: Result<CrudActionAftermath, CrudActionAftermath>,
     },
     Reload,
+    Undo,
+    Redo,
+    SaveDraft,
+    RestoreDraft,
+    DiscardDraft,
 }
 
 #[derive(Properties, PartialEq)]
@@ -91,6 +107,9 @@ pub struct Props<T: 'static + CrudMainTrait> {
     pub on_delete: Callback<T::UpdateModel>,
     pub on_tab_selected: Callback<Label>,
     pub on_entity_action: Callback<CrudActionAftermath>,
+    pub on_dirty_changed: Callback<bool>,
+    /// Name of the surrounding instance. Identifies the drafts of this view.
+    pub instance_name: String,
 }
 
 pub struct CrudEditView<T: CrudMainTrait> {
@@ -99,8 +118,8 @@ pub struct CrudEditView<T: CrudMainTrait> {
     /// as an editable entity was already created and might therefore contain field for which no default is available.
     input: Option<T::UpdateModel>,
 
-    /// The input is 'dirty' if was changed: The current state of `input` is not the state we started with.
-    input_dirty: bool,
+    /// Tracks whether `input` was changed since the entity was loaded or saved.
+    form_state: FormState<T::UpdateModel>,
 
     /// The input is erroneous if at least one field is contained in this list.
     input_errors: HashMap<<T::UpdateModel as CrudDataTrait>::Field, String>,
@@ -109,6 +128,15 @@ pub struct CrudEditView<T: CrudMainTrait> {
 
     user_wants_to_leave: bool,
 
+    /// Set while the user reviews the changes. Holds what to do after saving them.
+    pending_review: Option<Then>,
+
+    drafts_dispatch: Dispatch<DraftsStore<T>>,
+    /// Unsaved changes from an earlier visit, which the user may restore.
+    draft: Option<Draft<T::UpdateModel>>,
+    /// Saves a draft of the input shortly after the user stopped changing it.
+    draft_timeout: Option<Timeout>,
+
     /// Stores the current state of the entity or an error, if no entity could be fetched.
     /// Note that, while the initial fetch request is ongoing, this is in the error state!
     ///
@@ -137,6 +165,63 @@ pub enum Then {
 impl<T: 'static + CrudMainTrait> CrudEditView<T> {
     // TODO: Remove this code duplication!
 
+    /// Sets the value of a field, keeping everything depending on the input up to date.
+    /// Returns whether the view must be rendered again.
+    fn set_value(
+        &mut self,
+        ctx: &Context<Self>,
+        field: <T::UpdateModel as CrudDataTrait>::Field,
+        value: Value,
+    ) -> bool {
+        let validation = field.validate(&value);
+        let elements = &ctx.props().config.elements;
+        let input = self.input.as_mut().expect("Entity to be already loaded");
+        let conditions = field_condition::evaluate_conditions(elements, input);
+        let value_changed = !field.get_value(input).is_same_as(&value);
+        field.set_value(input, value);
+        let computed_changed =
+            computed_field::apply(&ctx.props().static_config.computed_update_fields, input);
+        let conditions_changed = conditions != field_condition::evaluate_conditions(elements, input);
+        if conditions_changed {
+            let visible = field_condition::visible_fields(elements, input);
+            self.input_errors.retain(|field, _| visible.contains(field));
+        }
+        self.form_state.update(input);
+        // The current input supersedes an older draft.
+        self.draft = None;
+        self.schedule_draft(ctx);
+        // Rerender if the value or a computed value changed, as it affects whether fields are shown as modified,
+        // or if the error shown for this field or the visibility of fields changed.
+        let error_changed = match validation {
+            Ok(()) => self.input_errors.remove(&field).is_some(),
+            Err(err) => self.input_errors.insert(field, err.clone()) != Some(err),
+        };
+        value_changed || computed_changed || error_changed || conditions_changed
+    }
+
+    fn schedule_draft(&mut self, ctx: &Context<Self>) {
+        let link = ctx.link().clone();
+        self.draft_timeout = Some(Timeout::new(MILLIS_UNTIL_DRAFT_IS_SAVED, move || {
+            link.send_message(Msg::SaveDraft)
+        }));
+    }
+
+    fn discard_draft(&mut self, ctx: &Context<Self>) {
+        self.draft = None;
+        self.draft_timeout = None;
+        let name = ctx.props().instance_name.clone();
+        let id = ctx.props().id.clone();
+        self.drafts_dispatch
+            .reduce_mut(|state| state.remove_edit_draft(&name, &id));
+    }
+
+    fn field_changes(&self, ctx: &Context<Self>) -> Vec<FieldChange<T::UpdateModel>> {
+        match (&self.entity, &self.input) {
+            (Ok(entity), Some(input)) => field_changes(&ctx.props().config.elements, entity, input),
+            _ => Vec::new(),
+        }
+    }
+
     fn is_save_disabled(&self) -> bool {
         self.ongoing_save || !self.input_errors.is_empty()
     }
@@ -205,7 +290,12 @@ impl<T: 'static + CrudMainTrait> CrudEditView<T> {
         );
         if let Ok(entity) = &self.entity {
             self.input = Some(entity.clone());
-            self.input_dirty = false;
+            self.form_state.reset(entity.clone());
+            self.draft = self
+                .drafts_dispatch
+                .get()
+                .get_edit_draft(&ctx.props().instance_name, &ctx.props().id)
+                .filter(|draft| &draft.input != entity);
         }
     }
 
@@ -219,7 +309,8 @@ impl<T: 'static + CrudMainTrait> CrudEditView<T> {
             Ok(save_result) => match save_result {
                 SaveResult::Saved(saved) => {
                     self.input = Some(saved.entity.clone());
-                    self.input_dirty = false;
+                    self.form_state.reset(saved.entity.clone());
+                    self.discard_draft(ctx);
                     self._set_entity(Ok(saved.entity), ctx);
                 }
                 SaveResult::Aborted { reason: _ } => {
@@ -242,7 +333,33 @@ impl<T: 'static + CrudMainTrait> CrudEditView<T> {
         };
     }
 
-    fn save_entity(&self, ctx: &Context<Self>, and_then: Then) {
+    /// Runs the validators of all shown fields, as fields the user did not touch were not validated yet.
+    /// Errors of hidden fields are dropped. Returns whether the input is free of errors.
+    fn validate_input(&mut self, ctx: &Context<Self>) -> bool {
+        if let Some(input) = &self.input {
+            let visible = field_condition::visible_fields(&ctx.props().config.elements, input);
+            self.input_errors.retain(|field, _| visible.contains(field));
+            for field in visible {
+                if let Err(err) = field.validate(&field.get_value(input)) {
+                    self.input_errors.insert(field, err);
+                }
+            }
+        }
+        self.input_errors.is_empty()
+    }
+
+    /// Saves the input. If configured, the user has to review the changes first.
+    fn request_save(&mut self, ctx: &Context<Self>, and_then: Then) {
+        match ctx.props().config.review_changes && self.validate_input(ctx) {
+            true => self.pending_review = Some(and_then),
+            false => self.save_entity(ctx, and_then),
+        }
+    }
+
+    fn save_entity(&mut self, ctx: &Context<Self>, and_then: Then) {
+        if !self.validate_input(ctx) {
+            return;
+        }
         let entity = self.input.clone().expect("Entity to be already loaded");
         let condition = <T as CrudMainTrait>::UpdateModelId::fields_iter(&ctx.props().id)
             .map(|field| (field.name().to_owned(), field.to_value()))
@@ -272,10 +389,14 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
         CrudEditView::load_entity(ctx);
         Self {
             input: None,
-            input_dirty: false,
+            form_state: FormState::new(ctx.props().on_dirty_changed.clone()),
             input_errors: HashMap::new(),
             user_wants_to_activate: vec![],
             user_wants_to_leave: false,
+            pending_review: None,
+            drafts_dispatch: Dispatch::new(),
+            draft: None,
+            draft_timeout: None,
             entity: Err((
                 NoData::NotYetLoaded,
                 false,
@@ -294,7 +415,7 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
         match msg {
             Msg::Back => {
                 self.user_wants_to_leave = true;
-                match self.input_dirty {
+                match self.form_state.is_dirty() {
                     true => {
                         // Waiting for the modal to be resolved!
                         true
@@ -310,6 +431,8 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
                 true
             }
             Msg::BackApproved => {
+                self.form_state.discard();
+                self.discard_draft(ctx);
                 ctx.props().on_list.emit(());
                 false
             }
@@ -357,16 +480,37 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
                 }
             },
             Msg::Save => {
-                self.save_entity(ctx, Then::DoNothing);
+                self.request_save(ctx, Then::DoNothing);
                 true
             }
             Msg::SaveAndReturn => {
-                self.save_entity(ctx, Then::OpenListView);
-                false
+                self.request_save(ctx, Then::OpenListView);
+                true
             }
             Msg::SaveAndNew => {
-                self.save_entity(ctx, Then::OpenCreateView);
-                false
+                self.request_save(ctx, Then::OpenCreateView);
+                true
+            }
+            Msg::ReviewCanceled => {
+                self.pending_review = None;
+                true
+            }
+            Msg::ReviewConfirmed => {
+                if let Some(and_then) = self.pending_review.take() {
+                    self.save_entity(ctx, and_then);
+                }
+                true
+            }
+            Msg::RevertField(field) => {
+                let values = match (&self.entity, &self.input) {
+                    (Ok(entity), Some(input)) => Some((field.get_value(input), field.get_value(entity))),
+                    _ => None,
+                };
+                if let Some((current, original)) = values {
+                    self.form_state.record(field.clone(), current, original.clone());
+                    self.set_value(ctx, field, original);
+                }
+                true
             }
             Msg::Delete => {
                 match &self.entity {
@@ -384,20 +528,16 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
             Msg::ValueChanged((field, result)) => {
                 match result {
                     Ok(value) => {
-                        let input = self.input.as_mut().expect("Entity to be already loaded");
-                        field.set_value(input, value);
-                        self.input_errors.remove(&field);
-                        // We might only want to set this to true if the new value was actually different to the old value!
-                        match &self.entity {
-                            Ok(entity) => self.input_dirty = input != entity,
-                            Err(_) => self.input_dirty = false,
-                        }
-                        false
+                        let input = self.input.as_ref().expect("Entity to be already loaded");
+                        let history = (self.form_state.can_undo(), self.form_state.can_redo());
+                        self.form_state.record(field.clone(), field.get_value(input), value.clone());
+                        let history_changed =
+                            history != (self.form_state.can_undo(), self.form_state.can_redo());
+                        self.set_value(ctx, field, value) || history_changed
                     }
                     Err(err) => {
                         self.input_errors.insert(field, err);
-                        // When we want to render the errors, set this to true.
-                        false
+                        true
                     }
                 }
             }
@@ -466,6 +606,44 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
                     false
                 }
             }
+            Msg::Undo => match self.form_state.undo() {
+                Some((field, value)) => {
+                    self.set_value(ctx, field, value);
+                    true
+                }
+                None => false,
+            },
+            Msg::Redo => match self.form_state.redo() {
+                Some((field, value)) => {
+                    self.set_value(ctx, field, value);
+                    true
+                }
+                None => false,
+            },
+            Msg::SaveDraft => {
+                self.draft_timeout = None;
+                match self.input.clone().filter(|_| self.form_state.is_dirty()) {
+                    Some(input) => {
+                        let name = ctx.props().instance_name.clone();
+                        let id = ctx.props().id.clone();
+                        self.drafts_dispatch
+                            .reduce_mut(|state| state.save_edit_draft(&name, &id, Draft::new(input)));
+                    }
+                    None => self.discard_draft(ctx),
+                }
+                false
+            }
+            Msg::RestoreDraft => {
+                if let Some(draft) = self.draft.take() {
+                    self.form_state.update(&draft.input);
+                    self.input = Some(draft.input);
+                }
+                true
+            }
+            Msg::DiscardDraft => {
+                self.discard_draft(ctx);
+                true
+            }
             Msg::Reload => {
                 CrudEditView::load_entity(ctx);
                 // load_entity triggers an async operation. Handler will re-render!
@@ -476,26 +654,34 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         html! {
-            <div>
+            <div onkeydown={self.form_state.history_shortcuts(ctx.link().callback(|_| Msg::Undo), ctx.link().callback(|_| Msg::Redo))}>
                 {
                     match &self.entity {
-                        Ok(_entity) => {
+                        Ok(entity) => {
+                            let permissions = &ctx.props().static_config.permissions;
+                            let edit_allowed = permissions.entity_allowed(entity, EntityOperation::Edit);
                             html! {
                                 <>
                                 <div class={"crud-row crud-nav"}>
                                     <div class={"crud-col"}>
                                         <CrudBtnWrapper>
-                                            <CrudBtn name={"Speichern"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::Save)}>
-                                                <CrudBtn name={"Speichern und zurück"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::SaveAndReturn)} />
-                                                <CrudBtn name={"Speichern und neu"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::SaveAndNew)} />
-                                            </CrudBtn>
-                                            <CrudBtn name={"Löschen"} variant={Variant::Danger} disabled={self.is_delete_disabled()} onclick={ctx.link().callback(|_| Msg::Delete)} />
+                                            if edit_allowed {
+                                                <CrudBtn name={"Speichern"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::Save)}>
+                                                    <CrudBtn name={"Speichern und zurück"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::SaveAndReturn)} />
+                                                    <CrudBtn name={"Speichern und neu"} variant={Variant::Primary} disabled={self.is_save_disabled()} onclick={ctx.link().callback(|_| Msg::SaveAndNew)} />
+                                                </CrudBtn>
+                                                <CrudBtn name={"Rückgängig"} variant={Variant::Default} icon={Bi::ArrowCounterclockwise} disabled={!self.form_state.can_undo()} onclick={ctx.link().callback(|_| Msg::Undo)} />
+                                                <CrudBtn name={"Wiederholen"} variant={Variant::Default} icon={Bi::ArrowClockwise} disabled={!self.form_state.can_redo()} onclick={ctx.link().callback(|_| Msg::Redo)} />
+                                            }
+                                            if permissions.entity_allowed(entity, EntityOperation::Delete) {
+                                                <CrudBtn name={"Löschen"} variant={Variant::Danger} disabled={self.is_delete_disabled()} onclick={ctx.link().callback(|_| Msg::Delete)} />
+                                            }
 
                                             {
                                                 ctx.props().static_config.entity_actions.iter()
                                                     .filter_map(|action| match action {
                                                         CrudEntityAction::Custom {id, name, icon, variant, valid_in, action, modal} => {
-                                                            valid_in.contains(&States::Update).then(|| {
+                                                            (valid_in.contains(&States::Update) && permissions.entity_action_allowed(id, entity)).then(|| {
                                                                 let action_id: &str = (&id).clone();
                                                                 let action = action.clone();
 
@@ -550,6 +736,16 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
                                     </div>
                                 </div>
 
+                                if let Some(draft) = &self.draft {
+                                    <CrudAlert variant={crate::crud_alert::Variant::Info}>
+                                        {format!("Es gibt ungespeicherte Änderungen vom {}. Möchtest du sie wiederherstellen?", draft.saved_at_localized())}
+                                        <CrudBtnWrapper>
+                                            <CrudBtn name={"Wiederherstellen"} variant={Variant::Primary} onclick={ctx.link().callback(|_| Msg::RestoreDraft)} />
+                                            <CrudBtn name={"Verwerfen"} variant={Variant::Default} onclick={ctx.link().callback(|_| Msg::DiscardDraft)} />
+                                        </CrudBtnWrapper>
+                                    </CrudAlert>
+                                }
+
                                 <CrudFields<T::UpdateModel>
                                     api_base_url={ctx.props().config.api_base_url.clone()}
                                     children={ChildrenRenderer::new(ctx.props().children.iter().filter(|it| match it {
@@ -558,11 +754,16 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
                                         Item::Select(select) => select.props.for_model == crate::crud_reset_field::Model::Update,
                                     }).collect::<Vec<Item>>())}
                                     custom_fields={ctx.props().custom_fields.clone()}
-                                    elements={ctx.props().config.elements.clone()}
+                                    elements={computed_field::disable_computed(
+                                        &ctx.props().static_config.computed_update_fields,
+                                        permissions.restrict_elements(&CrudSimpleView::Edit, &ctx.props().config.elements),
+                                    )}
                                     entity={self.input.clone()}
-                                    mode={FieldMode::Editable}
+                                    original={self.entity.clone().ok()}
+                                    mode={match edit_allowed { true => FieldMode::Editable, false => FieldMode::Readable }}
                                     current_view={CrudSimpleView::Edit}
                                     value_changed={ctx.link().callback(Msg::ValueChanged)}
+                                    errors={self.input_errors.clone()}
                                     active_tab={ctx.props().config.active_tab.clone()}
                                     on_tab_selection={ctx.link().callback(|label| Msg::TabSelected(label))}
                                 />
@@ -593,6 +794,16 @@ impl<T: 'static + CrudMainTrait> Component for CrudEditView<T> {
                         }
                     }
                 }
+                if self.pending_review.is_some() {
+                    <CrudModal>
+                        <CrudReviewChangesModal<T::UpdateModel>
+                            changes={self.field_changes(ctx)}
+                            on_revert={ctx.link().callback(Msg::RevertField)}
+                            on_cancel={ctx.link().callback(|_| Msg::ReviewCanceled)}
+                            on_confirm={ctx.link().callback(|_| Msg::ReviewConfirmed)}
+                        />
+                    </CrudModal>
+                }
                 if self.user_wants_to_leave {
                     <CrudModal>
                         <CrudLeaveModal
@@ -1,11 +1,18 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, str::FromStr};
 
 use crate::{
-    crud_instance::Item, crud_offset_datetime::CrudOffsetDatetime, event_target_as,
-    keyboard_event_target_as, types::custom_field::CustomFields, DateTimeDisplay,
+    crud_instance::Item, crud_offset_datetime::CrudOffsetDatetime, crud_tag_input::render_tags, event_target_as,
+    keyboard_event_target_as,
+    types::{
+        custom_field::CustomFields,
+        duration::{format_duration, parse_duration, ParseDurationError},
+        formatter::Formatted,
+    },
+    DateTimeDisplay,
 };
 
 use super::prelude::*;
+use rust_decimal::Decimal;
 use time::{format_description::well_known::Rfc3339, macros::format_description, UtcOffset};
 use tracing::error;
 use uuid::Uuid;
@@ -16,7 +23,8 @@ use yew_bootstrap_icons::v1_10_3::Bi;
 
 pub enum Msg {
     Send(Value),
-    // TODO: Add SendErr variant...?
+    /// The input could not be turned into a value. The error is shown at the field and prevents saving.
+    SendErr(String),
     LogInputRetrievalErr(Box<dyn std::error::Error>),
 }
 
@@ -31,6 +39,10 @@ pub struct Props<T: CrudDataTrait> {
     pub field_mode: FieldMode,
     // TODO: Must not OWN the complete entity!
     pub entity: Option<T>,
+    /// The saved state of the entity. If the value of this field differs from it,
+    /// the field is marked as modified and can be reverted to its saved value.
+    #[prop_or_default]
+    pub original: Option<T>,
     pub value_changed: Callback<(T::Field, Result<Value, String>)>, // how can we handle all possible types? serialization?
 }
 
@@ -40,10 +52,61 @@ pub struct CrudField<T> {
     id: Uuid,
 }
 
-impl<T: CrudDataTrait> CrudField<T> {
+impl<T: 'static + CrudDataTrait> CrudField<T> {
     fn format_id(&self) -> String {
         format!("f{}", self.id.to_string())
     }
+
+    /// The saved value of this field, if the current value differs from it.
+    fn original_value(&self, ctx: &Context<Self>) -> Option<Value> {
+        let field = &ctx.props().field_type;
+        match (&ctx.props().original, &self.entity) {
+            (Some(original), Some(entity)) => {
+                let original = field.get_value(original);
+                (!original.is_same_as(&field.get_value(entity))).then(|| original)
+            }
+            _ => None,
+        }
+    }
+
+    /// Lets the user remove the value of an optional field, which is then saved as `None`.
+    /// Deleting all characters of the input instead leaves an empty value.
+    fn render_clear_button(&self, ctx: &Context<Self>, has_value: bool, cleared: Value) -> Html {
+        match has_value && !ctx.props().field_options.disabled {
+            true => html! {
+                <CrudBtn
+                    name={"Leeren"}
+                    variant={Variant::Default}
+                    size={crate::crud_btn::Size::Small}
+                    icon={Bi::XLg}
+                    onclick={ctx.link().callback(move |_| Msg::Send(cleared.clone()))}
+                />
+            },
+            false => html! {},
+        }
+    }
+
+    fn render_modified(&self, ctx: &Context<Self>, field: Html, original: Value) -> Html {
+        html! {
+            <div class={"crud-field-modified"}>
+                {field}
+                <div class={"crud-field-modified-actions"}>
+                    <span class={"crud-field-modified-marker"} title={"Geändert"}>
+                        <CrudIcon variant={Bi::PencilFill}/>
+                    </span>
+                    if !ctx.props().field_options.disabled {
+                        <CrudBtn
+                            name={"Zurücksetzen"}
+                            variant={Variant::Default}
+                            size={crate::crud_btn::Size::Small}
+                            icon={Bi::ArrowCounterclockwise}
+                            onclick={ctx.link().callback(move |_| Msg::Send(original.clone()))}
+                        />
+                    }
+                </div>
+            </div>
+        }
+    }
 }
 
 impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
@@ -71,6 +134,12 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                     .emit((ctx.props().field_type.clone(), Ok(value)));
                 false
             }
+            Msg::SendErr(err) => {
+                ctx.props()
+                    .value_changed
+                    .emit((ctx.props().field_type.clone(), Err(err)));
+                false
+            }
             Msg::LogInputRetrievalErr(err) => {
                 error!("Could not get input value: {}", err);
                 false
@@ -79,7 +148,28 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let field = self.render_field(ctx);
+        match self.original_value(ctx) {
+            Some(original) if ctx.props().field_mode == FieldMode::Editable => {
+                self.render_modified(ctx, field, original)
+            }
+            _ => field,
+        }
+    }
+}
+
+impl<T: 'static + CrudDataTrait> CrudField<T> {
+    fn render_field(&self, ctx: &Context<Self>) -> Html {
         let options = &ctx.props().field_options;
+        if ctx.props().field_mode == FieldMode::Display {
+            let formatted = self
+                .entity
+                .as_ref()
+                .and_then(|entity| options.formatter.format(&ctx.props().field_type.get_value(entity)));
+            if let Some(formatted) = formatted {
+                return render_formatted(formatted);
+            }
+        }
         match &self.entity {
             Some(entity) => html! {
                 match ctx.props().field_type.get_value(entity) {
@@ -210,6 +300,49 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                             </div>
                         },
                     },
+                    Value::OptionalString(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            Some(value) => html! { <div>{value}</div> },
+                            None => html! { "-" },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    value={value.clone().unwrap_or_default()}
+                                    placeholder={"-"}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <div class={"crud-nullable-input"}>
+                                    <input
+                                        id={self.format_id()}
+                                        class={"crud-input-field"}
+                                        type={"text"}
+                                        value={value.clone().unwrap_or_default()}
+                                        placeholder={"-"}
+                                        onkeyup={ctx.link().callback(|event| match keyboard_event_target_as::<web_sys::HtmlInputElement>(event) {
+                                            Ok(input) => Msg::Send(Value::OptionalString(Some(input.value()))),
+                                            Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                        })}
+                                        onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                            Ok(input) => Msg::Send(Value::OptionalString(Some(input.value()))),
+                                            Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                        })}
+                                        disabled={options.disabled}
+                                    />
+                                    { self.render_clear_button(ctx, value.is_some(), Value::OptionalString(None)) }
+                                </div>
+                            </div>
+                        },
+                    },
                     Value::Text(value) => match &ctx.props().field_mode {
                         FieldMode::Display => html! {
                             <div>{value}</div>
@@ -240,6 +373,40 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                             </div>
                         },
                     },
+                    Value::OptionalText(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            Some(value) => html! { <div>{value}</div> },
+                            None => html! { "-" },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTipTapEditor
+                                    api_base_url={ctx.props().api_base_url.clone()}
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    value={value.clone().unwrap_or_default()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <div class={"crud-nullable-input"}>
+                                    <CrudTipTapEditor
+                                        api_base_url={ctx.props().api_base_url.clone()}
+                                        id={self.format_id()}
+                                        class={"crud-input-field"}
+                                        value={value.clone().unwrap_or_default()}
+                                        onchange={ctx.link().callback(|input| Msg::Send(Value::OptionalText(Some(input))))}
+                                        disabled={options.disabled}
+                                    />
+                                    { self.render_clear_button(ctx, value.is_some(), Value::OptionalText(None)) }
+                                </div>
+                            </div>
+                        },
+                    },
                     Value::Json(value) => match &ctx.props().field_mode {
                         FieldMode::Display => html! {
                             <div>{value.get_string_representation()}</div>
@@ -319,6 +486,26 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                             </div>
                         },
                     },
+                    Value::OptionalUuidV4(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            Some(value) => html! { <div>{value.to_string()}</div> },
+                            None => html! { "-" },
+                        },
+                        // Never editable
+                        FieldMode::Readable | FieldMode::Editable  => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
+                                    placeholder={"-"}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                    },
                     Value::UuidV7(value) => match &ctx.props().field_mode {
                         FieldMode::Display => html! {
                             <div>{value.to_string()}</div>
@@ -337,6 +524,26 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                             </div>
                         },
                     },
+                    Value::OptionalUuidV7(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            Some(value) => html! { <div>{value.to_string()}</div> },
+                            None => html! { "-" },
+                        },
+                        // Never editable
+                        FieldMode::Readable | FieldMode::Editable  => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
+                                    placeholder={"-"}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                    },
                     Value::U32(value) => match &ctx.props().field_mode {
                         FieldMode::Display => html! {
                             <div>{format!("{}", value)}</div>
@@ -647,70 +854,65 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                             </div>
                         },
                     },
-                    Value::Bool(value) => match &ctx.props().field_mode {
+                    Value::F64(value) => match &ctx.props().field_mode {
                         FieldMode::Display => html! {
                             <div>{format!("{}", value)}</div>
                         },
                         FieldMode::Readable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <div id={self.format_id()} class={"crud-input-field"}>
-                                    <CrudToggle
-                                        state={value}
-                                        disabled={true}
-                                    />
-                                </div>
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"number"}
+                                    step={"any"}
+                                    value={format!("{}", value)}
+                                    disabled={true}
+                                />
                             </div>
                         },
                         FieldMode::Editable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <div id={self.format_id()} class={"crud-input-field"}>
-                                    <CrudToggle
-                                        state={value}
-                                        on_toggle={ctx.link().callback(|value| Msg::Send(Value::Bool(value)))}
-                                        disabled={true}
-                                    />
-                                </div>
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"number"}
+                                    step={"any"}
+                                    value={format!("{}", value)}
+                                    onkeyup={ctx.link().callback(|event| match keyboard_event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match input.value().parse::<f64>() {
+                                            Ok(value) => Msg::Send(Value::F64(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match input.value().parse::<f64>() {
+                                            Ok(value) => Msg::Send(Value::F64(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    disabled={options.disabled}
+                                />
                             </div>
                         },
                     },
-                    Value::ValidationStatus(value) => match &ctx.props().field_mode {
+                    Value::OptionalF32(value) => match &ctx.props().field_mode {
                         FieldMode::Display => match value {
-                            true => html! {
-                                <CrudIcon variant={Bi::ExclamationTriangleFill} color={"#ffbd00"}/>
-                            },
-                            false => html! {
-                                <CrudIcon variant={Bi::CheckLg}  color={"#469a46"}/>
-                            },
-                        },
-                        FieldMode::Readable => html! {
-                            <div class="crud-field">
-                                { "Only displayable..." }
-                            </div>
-                        },
-                        FieldMode::Editable => html! {
-                            <div class="crud-field">
-                                { "Only displayable..." }
-                            </div>
-                        },
-                    },
-                    Value::PrimitiveDateTime(primitive_date_time) => match &ctx.props().field_mode {
-                        FieldMode::Display => match options.date_time_display {
-                            DateTimeDisplay::IsoUtc => html! {
-                                <div>{primitive_date_time.format(&Rfc3339).unwrap()}</div>
-                            },
-                            // TODO: Use icu4x formatting using the current users locale!
-                            DateTimeDisplay::LocalizedLocal => html! {
-                                <div>{primitive_date_time.format(format_description!("[day].[month].[year] [hour]:[minute]")).unwrap()}</div>
-                            },
+                            Some(value) => html! { <div>{format!("{}", value)}</div> },
+                            None => html! { "-" },
                         },
                         FieldMode::Readable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <CrudOffsetDatetime
+                                <input
                                     id={self.format_id()}
-                                    value={primitive_date_time.clone().assume_utc()}
+                                    class={"crud-input-field"}
+                                    type={"number"}
+                                    step={"any"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
                                     disabled={true}
                                 />
                             </div>
@@ -718,31 +920,45 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                         FieldMode::Editable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <CrudOffsetDatetime
+                                <input
                                     id={self.format_id()}
-                                    value={primitive_date_time.clone().assume_utc()}
-                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OffsetDateTime(datetime.expect("Expected OffsetDateTime to not be None!"))))}
+                                    class={"crud-input-field"}
+                                    type={"number"}
+                                    step={"any"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
+                                    onkeyup={ctx.link().callback(|event| match keyboard_event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional::<f32>(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalF32(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional::<f32>(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalF32(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
                                     disabled={options.disabled}
                                 />
                             </div>
                         },
                     },
-                    Value::OffsetDateTime(offset_date_time) => match &ctx.props().field_mode {
-                        FieldMode::Display => match options.date_time_display {
-                            DateTimeDisplay::IsoUtc => html! {
-                                <div>{offset_date_time.format(&Rfc3339).unwrap()}</div>
-                            },
-                            // TODO: Use icu4x formatting using the current users locale!
-                            DateTimeDisplay::LocalizedLocal => html! {
-                                <div>{offset_date_time.to_offset(UtcOffset::current_local_offset().unwrap()).format(format_description!("[day].[month].[year] [hour]:[minute]")).unwrap()}</div>
-                            },
+                    Value::OptionalF64(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            Some(value) => html! { <div>{format!("{}", value)}</div> },
+                            None => html! { "-" },
                         },
                         FieldMode::Readable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <CrudOffsetDatetime
+                                <input
                                     id={self.format_id()}
-                                    value={offset_date_time.clone()}
+                                    class={"crud-input-field"}
+                                    type={"number"}
+                                    step={"any"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
                                     disabled={true}
                                 />
                             </div>
@@ -750,41 +966,44 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                         FieldMode::Editable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <CrudOffsetDatetime
+                                <input
                                     id={self.format_id()}
-                                    value={offset_date_time.clone()}
-                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OffsetDateTime(datetime.expect("Expected OffsetDateTime to not be None!"))))}
+                                    class={"crud-input-field"}
+                                    type={"number"}
+                                    step={"any"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
+                                    onkeyup={ctx.link().callback(|event| match keyboard_event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional::<f64>(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalF64(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional::<f64>(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalF64(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
                                     disabled={options.disabled}
                                 />
                             </div>
                         },
                     },
-                    Value::OptionalPrimitiveDateTime(optional_primitive_date_time) => match &ctx.props().field_mode {
-                        FieldMode::Display => match options.date_time_display {
-                            DateTimeDisplay::IsoUtc => match optional_primitive_date_time {
-                                Some(date_time) => html! {
-                                    <div>{date_time.format(&Rfc3339).unwrap()}</div>
-                                },
-                                None => html! {
-                                    <div>{""}</div>
-                                },
-                            },
-                            DateTimeDisplay::LocalizedLocal => match optional_primitive_date_time {
-                                // TODO: Use icu4x formatting using the current users locale!
-                                Some(date_time) => html! {
-                                    <div>{date_time.format(format_description!("[day].[month].[year] [hour]:[minute]")).unwrap()}</div>
-                                },
-                                None => html! {
-                                    <div>{""}</div>
-                                },
-                            },
+                    Value::Decimal(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => html! {
+                            <div>{format!("{}", value)}</div>
                         },
                         FieldMode::Readable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <CrudOffsetDatetime
+                                <input
                                     id={self.format_id()}
-                                    value={optional_primitive_date_time.clone().map(|it| it.assume_utc())}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    inputmode={"decimal"}
+                                    value={format!("{}", value)}
                                     disabled={true}
                                 />
                             </div>
@@ -792,10 +1011,226 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                         FieldMode::Editable => html! {
                             <div class="crud-field">
                                 { render_label(&options) }
-                                <CrudOffsetDatetime
+                                <input
                                     id={self.format_id()}
-                                    value={optional_primitive_date_time.clone().map(|it| it.assume_utc())}
-                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OptionalOffsetDateTime(datetime)))}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    inputmode={"decimal"}
+                                    value={format!("{}", value)}
+                                    onkeyup={ctx.link().callback(|event| match keyboard_event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_decimal(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::Decimal(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_decimal(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::Decimal(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::OptionalDecimal(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            Some(value) => html! { <div>{format!("{}", value)}</div> },
+                            None => html! { "-" },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    inputmode={"decimal"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    inputmode={"decimal"}
+                                    value={value.map(|it| it.to_string()).unwrap_or_default()}
+                                    onkeyup={ctx.link().callback(|event| match keyboard_event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional_decimal(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalDecimal(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional_decimal(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalDecimal(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Zahl.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::Bool(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => html! {
+                            <div>{format!("{}", value)}</div>
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <div id={self.format_id()} class={"crud-input-field"}>
+                                    <CrudToggle
+                                        state={value}
+                                        disabled={true}
+                                    />
+                                </div>
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <div id={self.format_id()} class={"crud-input-field"}>
+                                    <CrudToggle
+                                        state={value}
+                                        on_toggle={ctx.link().callback(|value| Msg::Send(Value::Bool(value)))}
+                                        disabled={true}
+                                    />
+                                </div>
+                            </div>
+                        },
+                    },
+                    Value::ValidationStatus(value) => match &ctx.props().field_mode {
+                        FieldMode::Display => match value {
+                            true => html! {
+                                <CrudIcon variant={Bi::ExclamationTriangleFill} color={"#ffbd00"}/>
+                            },
+                            false => html! {
+                                <CrudIcon variant={Bi::CheckLg}  color={"#469a46"}/>
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { "Only displayable..." }
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { "Only displayable..." }
+                            </div>
+                        },
+                    },
+                    Value::PrimitiveDateTime(primitive_date_time) => match &ctx.props().field_mode {
+                        FieldMode::Display => match options.date_time_display {
+                            DateTimeDisplay::IsoUtc => html! {
+                                <div>{primitive_date_time.format(&Rfc3339).unwrap()}</div>
+                            },
+                            // TODO: Use icu4x formatting using the current users locale!
+                            DateTimeDisplay::LocalizedLocal => html! {
+                                <div>{primitive_date_time.format(format_description!("[day].[month].[year] [hour]:[minute]")).unwrap()}</div>
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={primitive_date_time.clone().assume_utc()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={primitive_date_time.clone().assume_utc()}
+                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OffsetDateTime(datetime.expect("Expected OffsetDateTime to not be None!"))))}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::OffsetDateTime(offset_date_time) => match &ctx.props().field_mode {
+                        FieldMode::Display => match options.date_time_display {
+                            DateTimeDisplay::IsoUtc => html! {
+                                <div>{offset_date_time.format(&Rfc3339).unwrap()}</div>
+                            },
+                            // TODO: Use icu4x formatting using the current users locale!
+                            DateTimeDisplay::LocalizedLocal => html! {
+                                <div>{offset_date_time.to_offset(UtcOffset::current_local_offset().unwrap()).format(format_description!("[day].[month].[year] [hour]:[minute]")).unwrap()}</div>
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={offset_date_time.clone()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={offset_date_time.clone()}
+                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OffsetDateTime(datetime.expect("Expected OffsetDateTime to not be None!"))))}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::OptionalPrimitiveDateTime(optional_primitive_date_time) => match &ctx.props().field_mode {
+                        FieldMode::Display => match options.date_time_display {
+                            DateTimeDisplay::IsoUtc => match optional_primitive_date_time {
+                                Some(date_time) => html! {
+                                    <div>{date_time.format(&Rfc3339).unwrap()}</div>
+                                },
+                                None => html! {
+                                    <div>{""}</div>
+                                },
+                            },
+                            DateTimeDisplay::LocalizedLocal => match optional_primitive_date_time {
+                                // TODO: Use icu4x formatting using the current users locale!
+                                Some(date_time) => html! {
+                                    <div>{date_time.format(format_description!("[day].[month].[year] [hour]:[minute]")).unwrap()}</div>
+                                },
+                                None => html! {
+                                    <div>{""}</div>
+                                },
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={optional_primitive_date_time.clone().map(|it| it.assume_utc())}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={optional_primitive_date_time.clone().map(|it| it.assume_utc())}
+                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OptionalOffsetDateTime(datetime)))}
                                     disabled={options.disabled}
                                 />
                             </div>
@@ -843,6 +1278,339 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
                             </div>
                         },
                     },
+                    Value::Date(date) => match &ctx.props().field_mode {
+                        FieldMode::Display => match options.date_time_display {
+                            DateTimeDisplay::IsoUtc => html! {
+                                <div>{date.format(format_description!("[year]-[month]-[day]")).unwrap()}</div>
+                            },
+                            // TODO: Use icu4x formatting using the current users locale!
+                            DateTimeDisplay::LocalizedLocal => html! {
+                                <div>{date.format(format_description!("[day].[month].[year]")).unwrap()}</div>
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={date.midnight().assume_utc()}
+                                    input_type={crate::crud_offset_datetime::Type::Date}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={date.midnight().assume_utc()}
+                                    input_type={crate::crud_offset_datetime::Type::Date}
+                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::Date(datetime.expect("Expected OffsetDateTime to not be None!").date())))}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::OptionalDate(optional_date) => match &ctx.props().field_mode {
+                        FieldMode::Display => match options.date_time_display {
+                            DateTimeDisplay::IsoUtc => match optional_date {
+                                Some(date) => html! {
+                                    <div>{date.format(format_description!("[year]-[month]-[day]")).unwrap()}</div>
+                                },
+                                None => html! {
+                                    <div>{""}</div>
+                                },
+                            },
+                            DateTimeDisplay::LocalizedLocal => match optional_date {
+                                // TODO: Use icu4x formatting using the current users locale!
+                                Some(date) => html! {
+                                    <div>{date.format(format_description!("[day].[month].[year]")).unwrap()}</div>
+                                },
+                                None => html! {
+                                    <div>{""}</div>
+                                },
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={optional_date.map(|it| it.midnight().assume_utc())}
+                                    input_type={crate::crud_offset_datetime::Type::Date}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <div class={"crud-nullable-input"}>
+                                    <CrudOffsetDatetime
+                                        id={self.format_id()}
+                                        value={optional_date.map(|it| it.midnight().assume_utc())}
+                                        input_type={crate::crud_offset_datetime::Type::Date}
+                                        onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OptionalDate(datetime.map(|it| it.date()))))}
+                                        disabled={options.disabled}
+                                    />
+                                    { self.render_clear_button(ctx, optional_date.is_some(), Value::OptionalDate(None)) }
+                                </div>
+                            </div>
+                        },
+                    },
+                    Value::Time(time_of_day) => match &ctx.props().field_mode {
+                        FieldMode::Display => html! {
+                            <div>{time_of_day.format(format_description!("[hour]:[minute]")).unwrap()}</div>
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={time::OffsetDateTime::UNIX_EPOCH.replace_time(*time_of_day)}
+                                    input_type={crate::crud_offset_datetime::Type::Time}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={time::OffsetDateTime::UNIX_EPOCH.replace_time(*time_of_day)}
+                                    input_type={crate::crud_offset_datetime::Type::Time}
+                                    onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::Time(datetime.expect("Expected OffsetDateTime to not be None!").time())))}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::OptionalTime(optional_time) => match &ctx.props().field_mode {
+                        FieldMode::Display => match optional_time {
+                            Some(time_of_day) => html! {
+                                <div>{time_of_day.format(format_description!("[hour]:[minute]")).unwrap()}</div>
+                            },
+                            None => html! {
+                                <div>{""}</div>
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudOffsetDatetime
+                                    id={self.format_id()}
+                                    value={optional_time.map(|it| time::OffsetDateTime::UNIX_EPOCH.replace_time(it))}
+                                    input_type={crate::crud_offset_datetime::Type::Time}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <div class={"crud-nullable-input"}>
+                                    <CrudOffsetDatetime
+                                        id={self.format_id()}
+                                        value={optional_time.map(|it| time::OffsetDateTime::UNIX_EPOCH.replace_time(it))}
+                                        input_type={crate::crud_offset_datetime::Type::Time}
+                                        onchange={ctx.link().callback(|datetime: Option<time::OffsetDateTime>| Msg::Send(Value::OptionalTime(datetime.map(|it| it.time()))))}
+                                        disabled={options.disabled}
+                                    />
+                                    { self.render_clear_button(ctx, optional_time.is_some(), Value::OptionalTime(None)) }
+                                </div>
+                            </div>
+                        },
+                    },
+                    Value::Duration(duration) => match &ctx.props().field_mode {
+                        FieldMode::Display => html! {
+                            <div>{format_duration(duration)}</div>
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    value={format_duration(duration)}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        // Parsed on change only, as reformatting the input while typing would interfere with the user.
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    placeholder={"z.B. 1h 30m"}
+                                    value={format_duration(duration)}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_duration(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::Duration(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Dauer, z.B. 1h 30m.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::OptionalDuration(optional_duration) => match &ctx.props().field_mode {
+                        FieldMode::Display => match optional_duration {
+                            Some(duration) => html! {
+                                <div>{format_duration(duration)}</div>
+                            },
+                            None => html! {
+                                <div>{""}</div>
+                            },
+                        },
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    value={optional_duration.as_ref().map(format_duration).unwrap_or_default()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        // Parsed on change only, as reformatting the input while typing would interfere with the user.
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <input
+                                    id={self.format_id()}
+                                    class={"crud-input-field"}
+                                    type={"text"}
+                                    placeholder={"z.B. 1h 30m"}
+                                    value={optional_duration.as_ref().map(format_duration).unwrap_or_default()}
+                                    onchange={ctx.link().callback(|event| match event_target_as::<web_sys::HtmlInputElement>(event) {
+                                        Ok(input) => match parse_optional_duration(&input.value()) {
+                                            Ok(value) => Msg::Send(Value::OptionalDuration(value)),
+                                            Err(_) => Msg::SendErr("Die Eingabe ist keine gültige Dauer, z.B. 1h 30m.".to_owned()),
+                                        }
+                                        Err(err) => Msg::LogInputRetrievalErr(err.into())
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::StringVec(values) => match &ctx.props().field_mode {
+                        FieldMode::Display => render_tags(values.iter().map(ToString::to_string)),
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    onchange={ctx.link().callback(|tags: Vec<String>| Msg::Send(Value::StringVec(tags)))}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::I32Vec(values) => match &ctx.props().field_mode {
+                        FieldMode::Display => render_tags(values.iter().map(ToString::to_string)),
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    input_type={"number"}
+                                    onchange={ctx.link().callback(|tags: Vec<String>| match parse_tags::<i32>(tags) {
+                                        Ok(values) => Msg::Send(Value::I32Vec(values)),
+                                        Err(tag) => Msg::SendErr(format!("\"{tag}\" ist keine gültige Zahl.")),
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::I64Vec(values) => match &ctx.props().field_mode {
+                        FieldMode::Display => render_tags(values.iter().map(ToString::to_string)),
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    input_type={"number"}
+                                    onchange={ctx.link().callback(|tags: Vec<String>| match parse_tags::<i64>(tags) {
+                                        Ok(values) => Msg::Send(Value::I64Vec(values)),
+                                        Err(tag) => Msg::SendErr(format!("\"{tag}\" ist keine gültige Zahl.")),
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
+                    Value::UuidVec(values) => match &ctx.props().field_mode {
+                        FieldMode::Display => render_tags(values.iter().map(ToString::to_string)),
+                        FieldMode::Readable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    disabled={true}
+                                />
+                            </div>
+                        },
+                        FieldMode::Editable => html! {
+                            <div class="crud-field">
+                                { render_label(&options) }
+                                <CrudTagInput
+                                    id={self.format_id()}
+                                    tags={values.iter().map(ToString::to_string).collect::<Vec<_>>()}
+                                    onchange={ctx.link().callback(|tags: Vec<String>| match parse_tags::<Uuid>(tags) {
+                                        Ok(values) => Msg::Send(Value::UuidVec(values)),
+                                        Err(tag) => Msg::SendErr(format!("\"{tag}\" ist keine gültige UUID.")),
+                                    })}
+                                    disabled={options.disabled}
+                                />
+                            </div>
+                        },
+                    },
                     Value::Select(selected) => match &ctx.props().field_mode {
                         FieldMode::Display => html!{format!("{:?}", selected)},
                         FieldMode::Readable => html! {
@@ -918,6 +1686,52 @@ impl<T: 'static + CrudDataTrait> Component for CrudField<T> {
     }
 }
 
+fn render_formatted(formatted: Formatted) -> Html {
+    match formatted {
+        Formatted::Text { text, title } => html! {
+            <div {title}>{text}</div>
+        },
+        Formatted::Icon(true) => html! {
+            <CrudIcon variant={Bi::CheckLg} color={"#469a46"}/>
+        },
+        Formatted::Icon(false) => html! {
+            <CrudIcon variant={Bi::XLg} color={"#c33b3b"}/>
+        },
+    }
+}
+
+/// Parses all tags. Fails with the first tag which could not be parsed.
+fn parse_tags<V: FromStr>(tags: Vec<String>) -> Result<Vec<V>, String> {
+    tags.into_iter().map(|tag| tag.parse().map_err(|_| tag)).collect()
+}
+
+/// Parses the input of a nullable number field. An empty input means no value.
+fn parse_optional<N: FromStr>(input: &str) -> Result<Option<N>, N::Err> {
+    match input.trim() {
+        "" => Ok(None),
+        input => input.parse().map(Some),
+    }
+}
+
+/// Parses a decimal number. Accepts "," as well as "." as decimal separator.
+fn parse_decimal(input: &str) -> Result<Decimal, rust_decimal::Error> {
+    input.trim().replace(',', ".").parse()
+}
+
+fn parse_optional_decimal(input: &str) -> Result<Option<Decimal>, rust_decimal::Error> {
+    match input.trim() {
+        "" => Ok(None),
+        input => parse_decimal(input).map(Some),
+    }
+}
+
+fn parse_optional_duration(input: &str) -> Result<Option<time::Duration>, ParseDurationError> {
+    match input.trim() {
+        "" => Ok(None),
+        input => parse_duration(input).map(Some),
+    }
+}
+
 fn render_label(options: &FieldOptions) -> Html {
     html! {
         if let Some(label) = &options.label {
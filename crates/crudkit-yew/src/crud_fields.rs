@@ -1,7 +1,10 @@
-use crate::{crud_instance::Item, types::custom_field::CustomFields};
+use crate::{
+    crud_instance::Item,
+    types::{custom_field::CustomFields, field_condition},
+};
 
 use super::prelude::*;
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 use yew::{html::ChildrenRenderer, prelude::*};
 
 pub enum Msg<T: CrudDataTrait> {
@@ -19,6 +22,15 @@ pub struct Props<T: CrudDataTrait> {
     pub mode: FieldMode,
     pub current_view: CrudSimpleView,
     pub value_changed: Callback<(T::Field, Result<Value, String>)>,
+    /// Errors shown below the fields they belong to.
+    #[prop_or_default]
+    pub errors: HashMap<T::Field, String>,
+    /// Disables all fields, as the enclosing group is disabled.
+    #[prop_or_default]
+    pub disabled: bool,
+    /// The saved state of the entity. Fields whose value differs from it are marked as modified.
+    #[prop_or_default]
+    pub original: Option<T>,
     pub active_tab: Option<Label>,
     pub on_tab_selection: Callback<Label>,
 }
@@ -51,12 +63,36 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        // Conditions are evaluated against the current input. Everything is shown as long as there is no input.
+        let holds = |condition: &Option<FieldCondition>| {
+            ctx.props()
+                .entity
+                .as_ref()
+                .map_or(true, |entity| field_condition::holds(condition, entity))
+        };
+        let group_disabled = |group: &Group<T>| ctx.props().disabled || !holds(&group.enabled_if);
+        // Tabs show how many of their fields were modified or are erroneous, as these might not be visible right now.
+        let tab_badge = |group: &Group<T>| {
+            ctx.props().entity.as_ref().and_then(|entity| {
+                let count = field_condition::visible_fields(&group.children, entity)
+                    .iter()
+                    .filter(|field| {
+                        ctx.props().errors.contains_key(*field)
+                            || ctx.props().original.as_ref().map_or(false, |original| {
+                                !field.get_value(original).is_same_as(&field.get_value(entity))
+                            })
+                    })
+                    .count();
+                (count > 0).then(|| count)
+            })
+        };
         html! {
             ctx.props().elements.iter().map(|elem| {
                 html! {
                     match elem {
                         Elem::Enclosing(enclosing) => {
                             match enclosing {
+                                Enclosing::None(group) if !holds(&group.visible_if) => html! {},
                                 Enclosing::None(group) => html! {
                                     <CrudFields<T>
                                         children={ctx.props().children.clone()}
@@ -67,6 +103,9 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
                                         mode={ctx.props().mode.clone()}
                                         current_view={ctx.props().current_view.clone()}
                                         value_changed={ctx.props().value_changed.clone()}
+                                        errors={ctx.props().errors.clone()}
+                                        original={ctx.props().original.clone()}
+                                        disabled={group_disabled(group)}
                                         active_tab={ctx.props().active_tab.clone()}
                                         on_tab_selection={ctx.link().callback(|label| Msg::TabSelected(label))}
                                     />
@@ -77,9 +116,9 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
                                         on_tab_selection={ctx.link().callback(|label| Msg::TabSelected(label))}
                                     >
                                         {
-                                            for tabs.iter().map(|tab| {
+                                            for tabs.iter().filter(|tab| holds(&tab.group.visible_if)).map(|tab| {
                                                 html_nested! {
-                                                    <CrudTab label={tab.label.clone()}>
+                                                    <CrudTab label={tab.label.clone()} badge={tab_badge(&tab.group)}>
                                                         <CrudFields<T>
                                                             children={ctx.props().children.clone()}
                                                             custom_fields={ctx.props().custom_fields.clone()}
@@ -89,6 +128,9 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
                                                             mode={ctx.props().mode.clone()}
                                                             current_view={ctx.props().current_view.clone()}
                                                             value_changed={ctx.props().value_changed.clone()}
+                                                            errors={ctx.props().errors.clone()}
+                                                            original={ctx.props().original.clone()}
+                                                            disabled={group_disabled(&tab.group)}
                                                             active_tab={ctx.props().active_tab.clone()}
                                                             on_tab_selection={ctx.link().callback(|label| Msg::TabSelected(label))}
                                                         />
@@ -98,6 +140,7 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
                                         }
                                     </CrudTabs>
                                 },
+                                Enclosing::Card(group) if !holds(&group.visible_if) => html! {},
                                 Enclosing::Card(group) => html! {
                                     <div class={"crud-card"}>
                                         <CrudFields<T>
@@ -109,6 +152,9 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
                                             mode={ctx.props().mode.clone()}
                                             current_view={ctx.props().current_view.clone()}
                                             value_changed={ctx.props().value_changed.clone()}
+                                            errors={ctx.props().errors.clone()}
+                                            original={ctx.props().original.clone()}
+                                            disabled={group_disabled(group)}
                                             active_tab={ctx.props().active_tab.clone()}
                                             on_tab_selection={ctx.link().callback(|label| Msg::TabSelected(label))}
                                         />
@@ -116,19 +162,29 @@ impl<T: 'static + CrudDataTrait> Component for CrudFields<T> {
                                 },
                             }
                         },
+                        Elem::Field((_field_type, field_options)) if !holds(&field_options.visible_if) => html! {},
                         Elem::Field((field_type, field_options)) => {
+                            let enabled = !ctx.props().disabled && holds(&field_options.enabled_if);
+                            let mut field_options = field_options.clone();
+                            field_options.disabled |= !enabled;
                             html! {
+                                <>
                                 <CrudField<T>
                                     children={ctx.props().children.clone()}
                                     custom_fields={ctx.props().custom_fields.clone()}
                                     api_base_url={ctx.props().api_base_url.clone()}
                                     current_view={ctx.props().current_view.clone()}
                                     field_type={field_type.clone()}
-                                    field_options={field_options.clone()}
+                                    field_options={field_options}
                                     field_mode={ctx.props().mode}
                                     entity={ctx.props().entity.clone()}
+                                    original={ctx.props().original.clone()}
                                     value_changed={ctx.link().callback(Msg::ValueChanged)}
                                 />
+                                if let Some(error) = ctx.props().errors.get(field_type) {
+                                    <div class={"crud-field-error"}>{error.clone()}</div>
+                                }
+                                </>
                             }
                         },
                         Elem::Separator => {
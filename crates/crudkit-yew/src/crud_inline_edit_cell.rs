@@ -0,0 +1,254 @@
+use crudkit_condition::IntoAllEqualCondition;
+use crudkit_id::Id;
+use crudkit_id::IdField;
+use crudkit_shared::SaveResult;
+use tracing::warn;
+use yew::{html::ChildrenRenderer, prelude::*};
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use crate::{
+    crud_instance::Item,
+    keyboard_event_target_as,
+    services::crud_rest_data_provider::{CrudRestDataProvider, UpdateOne},
+    types::custom_field::{CustomReadFields, CustomUpdateFields},
+};
+
+use super::{prelude::*, types::RequestError};
+
+pub enum Msg<T: CrudMainTrait> {
+    ValueChanged(
+        (
+            <T::UpdateModel as CrudDataTrait>::Field,
+            Result<Value, String>,
+        ),
+    ),
+    KeyDown(KeyboardEvent),
+    Save,
+    Saved(Result<SaveResult<T::UpdateModel>, RequestError>),
+    Close,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props<T: 'static + CrudMainTrait> {
+    pub children: ChildrenRenderer<Item>,
+    pub custom_read_fields: CustomReadFields<T>,
+    pub custom_update_fields: CustomUpdateFields<T>,
+    pub api_base_url: String,
+    pub data_provider: CrudRestDataProvider<T>,
+    /// The row being edited.
+    pub entity: T::ReadModel,
+    /// The column being edited. The UpdateModel field with the same name is used for editing.
+    pub field: <T::ReadModel as CrudDataTrait>::Field,
+    pub header_options: HeaderOptions,
+    pub on_saved: Callback<T::UpdateModel>,
+    pub on_close: Callback<()>,
+}
+
+enum State {
+    Editing,
+    Saving,
+    /// The users input was discarded. The original value is shown alongside the reason.
+    Failed(String),
+}
+
+/// Edits a single field of a list entry in place.
+/// The ReadModel of the row is converted into an UpdateModel, which is then saved on its own.
+pub struct CrudInlineEditCell<T: CrudMainTrait> {
+    field: Option<<T::UpdateModel as CrudDataTrait>::Field>,
+    input: T::UpdateModel,
+    input_error: Option<String>,
+    state: State,
+}
+
+impl<T: 'static + CrudMainTrait> CrudInlineEditCell<T> {
+    fn save(&mut self, ctx: &Context<Self>) {
+        let entity = self.input.clone();
+        let condition = <T as CrudMainTrait>::UpdateModelId::fields_iter(&entity.get_id())
+            .map(|field| (field.name().to_owned(), field.to_value()))
+            .into_all_equal_condition();
+        let data_provider = ctx.props().data_provider.clone();
+        self.state = State::Saving;
+        ctx.link().send_future(async move {
+            Msg::Saved(
+                data_provider
+                    .update_one(UpdateOne {
+                        entity,
+                        condition: Some(condition),
+                    })
+                    .await,
+            )
+        });
+    }
+
+    fn fail(&mut self, ctx: &Context<Self>, reason: String) {
+        // Revert the users input. Only the original value is shown from now on.
+        self.input = ctx.props().entity.clone().into();
+        self.state = State::Failed(reason);
+    }
+
+    /// Whether the event originates from a textarea or a rich text editor, like the one used for `Value::Text`.
+    fn in_multiline_input(event: &KeyboardEvent) -> bool {
+        keyboard_event_target_as::<web_sys::HtmlElement>(event.clone())
+            .map_or(false, |target| target.is_content_editable() || target.tag_name() == "TEXTAREA")
+    }
+
+    fn field_options(ctx: &Context<Self>) -> FieldOptions {
+        FieldOptions {
+            date_time_display: ctx.props().header_options.date_time_display,
+            formatter: ctx.props().header_options.formatter.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: 'static + CrudMainTrait> Component for CrudInlineEditCell<T> {
+    type Message = Msg<T>;
+    type Properties = Props<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let name = ctx.props().field.get_name();
+        let field = T::UpdateModel::get_all_fields()
+            .into_iter()
+            .find(|field| field.get_name() == name);
+        Self {
+            state: match field {
+                Some(_) => State::Editing,
+                None => State::Failed("Dieses Feld kann nicht bearbeitet werden.".to_owned()),
+            },
+            field,
+            input: ctx.props().entity.clone().into(),
+            input_error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ValueChanged((field, result)) => match result {
+                Ok(value) => {
+                    field.set_value(&mut self.input, value);
+                    self.input_error.take().is_some()
+                }
+                Err(err) => {
+                    self.input_error = Some(err);
+                    true
+                }
+            },
+            Msg::KeyDown(event) => match event.key().as_str() {
+                // Enter inserts a line break in multiline inputs. Those are saved using Ctrl+Enter.
+                "Enter" if event.ctrl_key() || !Self::in_multiline_input(&event) => {
+                    event.prevent_default();
+                    ctx.link().send_message(Msg::Save);
+                    false
+                }
+                "Escape" => {
+                    ctx.props().on_close.emit(());
+                    false
+                }
+                _ => false,
+            },
+            Msg::Save => match (&self.state, &self.input_error) {
+                (State::Editing, None) => {
+                    self.save(ctx);
+                    true
+                }
+                _ => false,
+            },
+            Msg::Saved(result) => {
+                match result {
+                    Ok(SaveResult::Saved(saved)) => {
+                        ctx.props().on_saved.emit(saved.entity);
+                        ctx.props().on_close.emit(());
+                    }
+                    Ok(SaveResult::Aborted { reason }) => {
+                        self.fail(ctx, format!("Speichern abgebrochen: {reason}"));
+                    }
+                    Ok(SaveResult::CriticalValidationErrors) => {
+                        self.fail(
+                            ctx,
+                            "Kritische Validierungsfehler verhindern das Speichern.".to_owned(),
+                        );
+                    }
+                    Err(err) => {
+                        warn!("Inline edit could not be saved: {err:?}");
+                        self.fail(ctx, format!("Der Eintrag konnte nicht aktualisiert werden: {err}."));
+                    }
+                }
+                true
+            }
+            Msg::Close => {
+                ctx.props().on_close.emit(());
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        match (&self.state, &self.field) {
+            (State::Editing, Some(field)) => html! {
+                <div class={"crud-inline-edit editing"} onkeydown={ctx.link().callback(Msg::KeyDown)}>
+                    <CrudField<T::UpdateModel>
+                        children={ctx.props().children.clone()}
+                        custom_fields={ctx.props().custom_update_fields.clone()}
+                        api_base_url={ctx.props().api_base_url.clone()}
+                        current_view={CrudSimpleView::Edit}
+                        field_type={field.clone()}
+                        field_options={Self::field_options(ctx)}
+                        field_mode={FieldMode::Editable}
+                        entity={Some(self.input.clone())}
+                        value_changed={ctx.link().callback(Msg::ValueChanged)}
+                    />
+                    <div class={"action-icons"}>
+                        <div
+                            class={classes!("action-icon", self.input_error.is_some().then(|| "disabled"))}
+                            title={self.input_error.clone().unwrap_or_else(|| "Speichern".to_owned())}
+                            onclick={ctx.link().callback(|_| Msg::Save)}
+                        >
+                            <CrudIcon variant={Bi::Check}/>
+                        </div>
+                        <div class={"action-icon"} title={"Abbrechen"} onclick={ctx.link().callback(|_| Msg::Close)}>
+                            <CrudIcon variant={Bi::X}/>
+                        </div>
+                    </div>
+                </div>
+            },
+            (State::Saving, Some(field)) => html! {
+                <div class={"crud-inline-edit saving"}>
+                    <CrudField<T::UpdateModel>
+                        children={ctx.props().children.clone()}
+                        custom_fields={ctx.props().custom_update_fields.clone()}
+                        api_base_url={ctx.props().api_base_url.clone()}
+                        current_view={CrudSimpleView::List}
+                        field_type={field.clone()}
+                        field_options={Self::field_options(ctx)}
+                        field_mode={FieldMode::Display}
+                        entity={Some(self.input.clone())}
+                        value_changed={|_| {}}
+                    />
+                    <CrudIcon variant={Bi::HourglassSplit}/>
+                </div>
+            },
+            (_, _) => html! {
+                <div class={"crud-inline-edit failed"}>
+                    <CrudField<T::ReadModel>
+                        children={ctx.props().children.clone()}
+                        custom_fields={ctx.props().custom_read_fields.clone()}
+                        api_base_url={ctx.props().api_base_url.clone()}
+                        current_view={CrudSimpleView::List}
+                        field_type={ctx.props().field.clone()}
+                        field_options={Self::field_options(ctx)}
+                        field_mode={FieldMode::Display}
+                        entity={Some(ctx.props().entity.clone())}
+                        value_changed={|_| {}}
+                    />
+                    <div
+                        class={"action-icon"}
+                        title={match &self.state { State::Failed(reason) => reason.clone(), _ => String::new() }}
+                        onclick={ctx.link().callback(|_| Msg::Close)}
+                    >
+                        <CrudIcon variant={Bi::ExclamationTriangle} color={"red"}/>
+                    </div>
+                </div>
+            },
+        }
+    }
+}
@@ -2,11 +2,13 @@ use crudkit_condition::{Condition, ConditionClause, ConditionElement};
 use crudkit_id::{Id, SerializableId};
 use crudkit_shared::{DeleteResult, Order, Saved};
 
+use gloo::events::EventListener;
 use indexmap::{indexmap, IndexMap};
 use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 use tracing::{error, info, warn};
 use uuid::Uuid;
+use wasm_bindgen::{JsCast, JsValue};
 use yew::{
     html::{ChildrenRenderer, Scope},
     prelude::*,
@@ -16,7 +18,10 @@ use yewdux::prelude::*;
 
 use crate::{
     services::crud_rest_data_provider::{CrudRestDataProvider, DeleteById},
-    types::custom_field::{CustomCreateFields, CustomReadFields, CustomUpdateFields},
+    types::{
+        computed_field::{ComputedCreateFields, ComputedReadFields, ComputedUpdateFields},
+        custom_field::{CustomCreateFields, CustomReadFields, CustomUpdateFields},
+    },
 };
 
 use super::{prelude::*, stores, types::RequestError};
@@ -24,6 +29,9 @@ use super::{prelude::*, stores, types::RequestError};
 pub enum Msg<T: 'static + CrudMainTrait> {
     InstanceConfigStoreUpdated(Rc<stores::instance::InstanceStore<T>>),
     InstanceViewsStoreUpdated(Rc<stores::instance_views::InstanceViewsStore>),
+    ActiveInstanceUpdated(Rc<stores::active_instance::ActiveInstance>),
+    /// The user clicked or focused something. The instance becomes the active one, if it is the innermost instance around the target.
+    Interacted(Option<web_sys::EventTarget>),
     ViewLinked(Option<ViewLink<T>>),
     List,
     Create,
@@ -50,6 +58,9 @@ pub enum Msg<T: 'static + CrudMainTrait> {
     PageSelected(u64),
     ItemCountSelected(u64),
     TabSelected(Label),
+    HeadersChanged(Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>),
+    HeadersReset,
+    ColumnResized((<T::ReadModel as CrudDataTrait>::Field, u32)),
     EntityAction((Rc<Box<dyn CrudActionTrait>>, T::ReadModel)),
     CustomEntityAction(CrudActionAftermath),
     GlobalAction(CrudActionAftermath),
@@ -58,6 +69,10 @@ pub enum Msg<T: 'static + CrudMainTrait> {
     GetInput((CreateOrUpdateField<T>, Box<dyn FnOnce(Value)>)),
     Reset,
     Reload,
+    /// The create or edit view reports whether its form contains unsaved changes.
+    FormDirtyChanged(bool),
+    LeaveCanceled,
+    LeaveApproved,
 }
 
 // TODO: Location in source code?
@@ -79,18 +94,73 @@ pub struct NestedConfig {
     pub reference_field: String, // TODO: This should be: T::ReadModel::Field? (ClusterCertificateField::CreatedAt)
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ListMode {
+    /// Entities are shown page by page, `items_per_page` at a time.
+    Paginated,
+    /// Entities are loaded in chunks of `items_per_page` while the user scrolls. Only the visible rows are rendered.
+    InfiniteScroll {
+        /// Fixed height of a single row in pixels. Rows must not grow beyond this height.
+        /// Rows can therefore not be expanded to show their details in this mode.
+        row_height: u32,
+        /// Height of the scrollable area in pixels.
+        viewport_height: u32,
+    },
+}
+
+impl Default for ListMode {
+    fn default() -> Self {
+        Self::Paginated
+    }
+}
+
+/// How the entities of the list view are presented.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ListPresentation<T: CrudMainTrait> {
+    Table,
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(bound = "")]
+    Cards(CardLayout<T::ReadModel>),
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(bound = "")]
+    Calendar(CalendarLayout<T::ReadModel>),
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(bound = "")]
+    Kanban(KanbanLayout<T::ReadModel>),
+}
+
+impl<T: CrudMainTrait> Default for ListPresentation<T> {
+    fn default() -> Self {
+        Self::Table
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrudInstanceConfig<T: CrudMainTrait> {
     pub api_base_url: String,
     pub view: CrudView<T::ReadModelId, T::UpdateModelId>,
     pub headers: Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>,
+    #[serde(default)]
+    pub table_options: TableOptions,
     // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
     #[serde(bound = "")]
     pub create_elements: CreateElements<T>,
     // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
     #[serde(bound = "")]
     pub elements: Vec<Elem<T::UpdateModel>>,
+    /// Layout of the details shown when expanding a row of the list view.
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(default, bound = "")]
+    pub detail_elements: DetailElements<T>,
+    /// Lets the user review all changes made in the edit view before they are saved.
+    #[serde(default)]
+    pub review_changes: bool,
     pub order_by: IndexMap<<T::ReadModel as CrudDataTrait>::Field, Order>,
+    #[serde(default)]
+    pub list_mode: ListMode,
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(default, bound = "")]
+    pub list_presentation: ListPresentation<T>,
     pub items_per_page: u64,
     pub page: u64,
     pub active_tab: Option<Label>,
@@ -104,6 +174,18 @@ pub struct CrudStaticInstanceConfig<T: CrudMainTrait> {
     pub custom_read_fields: CustomReadFields<T>,
     pub custom_create_fields: CustomCreateFields<T>,
     pub custom_update_fields: CustomUpdateFields<T>,
+    /// Virtual columns of the list view, computed on the client after loading the entities.
+    /// Declare these fields on the read model with `#[serde(skip)]` and disallow ordering by them,
+    /// as they do not exist on the server.
+    pub computed_read_fields: ComputedReadFields<T>,
+    pub computed_create_fields: ComputedCreateFields<T>,
+    pub computed_update_fields: ComputedUpdateFields<T>,
+    /// Renders the details of an expanded row of the list view. Takes precedence over `CrudInstanceConfig::detail_elements`.
+    pub row_details: Option<RowDetails<T::ReadModel>>,
+    /// Decides what the user may see and do. Allows everything by default.
+    pub permissions: PermissionPolicy<T>,
+    /// Columns of the board, used if the list is presented as `ListPresentation::Kanban`.
+    pub kanban_columns: Vec<KanbanColumn>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -112,6 +194,19 @@ pub enum CreateElements<T: CrudMainTrait> {
     Custom(Vec<Elem<T::CreateModel>>),
 }
 
+/// Rows of the list view can only be expanded if details are configured.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DetailElements<T: CrudMainTrait> {
+    None,
+    Custom(Vec<Elem<T::ReadModel>>),
+}
+
+impl<T: CrudMainTrait> Default for DetailElements<T> {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
 impl<T: CrudMainTrait> Default for CrudInstanceConfig<T> {
     fn default() -> Self {
         Self {
@@ -127,12 +222,17 @@ impl<T: CrudMainTrait> Default for CrudInstanceConfig<T> {
             //     },
             // )],
             headers: vec![],
+            table_options: TableOptions::default(),
             create_elements: CreateElements::None,
             elements: vec![],
+            detail_elements: DetailElements::None,
+            review_changes: false,
             // order_by: indexmap! { // TODO: Nothing? First id field? All id fields?
             //     T::ReadModel::get_id_field() => Order::Asc,
             // },
             order_by: indexmap! {},
+            list_mode: ListMode::default(),
+            list_presentation: ListPresentation::default(),
             items_per_page: 10,
             page: 1,
             active_tab: None,
@@ -218,6 +318,10 @@ pub struct CrudInstance<T: 'static + CrudMainTrait> {
     instance_views_dispatch: Dispatch<stores::instance_views::InstanceViewsStore>,
     instance_links_dispatch: Dispatch<stores::instance_links::InstanceLinksStore<T>>,
     toasts_dispatch: Dispatch<stores::toasts::Toasts>,
+    active_instance_dispatch: Dispatch<stores::active_instance::ActiveInstance>,
+    /// Whether this is the instance the user interacted with last.
+    active: bool,
+    root: NodeRef,
 
     /// Initially `None`, when no view was yet created, otherwise present for 99% of this instances lifetime.
     view_link: Option<ViewLink<T>>,
@@ -227,6 +331,13 @@ pub struct CrudInstance<T: 'static + CrudMainTrait> {
     data_provider: CrudRestDataProvider<T>,
     entity_to_delete: Option<DeletableModel<T::ReadModel, T::UpdateModel>>,
     parent_id: Option<SerializableId>,
+
+    /// Whether the form of the current create or edit view contains unsaved changes.
+    form_dirty: bool,
+    /// Asks the browser to confirm closing or reloading the page while `form_dirty` is set.
+    before_unload_guard: Option<EventListener>,
+    /// A view switch waiting for the user to confirm that unsaved changes may be lost.
+    pending_view: Option<CrudView<T::ReadModelId, T::UpdateModelId>>,
 }
 
 impl<T: 'static + CrudMainTrait> CrudInstance<T> {
@@ -245,13 +356,38 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
             .reduce_mut(|state| state.save(name, serializable_view));
     }
 
+    /// Switches to the given view. Leaving a form with unsaved changes must be confirmed by the user first.
     fn set_view(&mut self, view: CrudView<T::ReadModelId, T::UpdateModelId>) {
-        self.config.view = view;
+        match self.form_dirty && view != self.config.view {
+            true => self.pending_view = Some(view),
+            false => self.config.view = view,
+        }
+    }
+
+    fn set_form_dirty(&mut self, dirty: bool) {
+        if self.form_dirty == dirty {
+            return;
+        }
+        self.form_dirty = dirty;
+        self.before_unload_guard = dirty.then(|| {
+            EventListener::new(&gloo::utils::window(), "beforeunload", |event| {
+                // Browsers show their own message, asking the user whether the page should really be left.
+                event.prevent_default();
+                if let Some(event) = event.dyn_ref::<web_sys::BeforeUnloadEvent>() {
+                    event.set_return_value("Ungespeicherte Änderungen gehen verloren!");
+                }
+            })
+        });
     }
 
     fn render(&self, ctx: &Context<CrudInstance<T>>) -> Html {
         html! {
-            <div class={"crud-instance"}>
+            <div
+                class={"crud-instance"}
+                ref={self.root.clone()}
+                onmousedown={ctx.link().callback(|event: MouseEvent| Msg::Interacted(event.target()))}
+                onfocusin={ctx.link().callback(|event: FocusEvent| Msg::Interacted(event.target()))}
+            >
                 <div class={"body"}>
                     {
                         match &self.config.view {
@@ -263,6 +399,10 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                                         custom_fields={self.static_config.custom_read_fields.clone()}
                                         config={self.config.clone()}
                                         static_config={self.static_config.clone()}
+                                        default_headers={ctx.props().config.headers.clone()}
+                                        on_headers_changed={ctx.link().callback(Msg::HeadersChanged)}
+                                        on_headers_reset={ctx.link().callback(|_| Msg::HeadersReset)}
+                                        on_column_resized={ctx.link().callback(Msg::ColumnResized)}
                                         on_reset={ctx.link().callback(|_| Msg::Reset)}
                                         on_create={ctx.link().callback(|_| Msg::Create)}
                                         on_read={ctx.link().callback(Msg::Read)}
@@ -275,6 +415,8 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                                         on_global_action={ctx.link().callback(Msg::GlobalAction)}
                                         on_link={ctx.link().callback(|link: Option<Scope<CrudListView<T>>>|
                                             Msg::ViewLinked(link.map(|link| ViewLink::List(link))))}
+                                        active={self.active}
+                                        modal_open={self.entity_to_delete.is_some() || self.pending_view.is_some()}
                                     />
                                 }
                             },
@@ -286,7 +428,9 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                                         children={ctx.props().children.clone()}
                                         custom_create_fields={self.static_config.custom_create_fields.clone()}
                                         custom_update_fields={self.static_config.custom_update_fields.clone()}
+                                        computed_create_fields={self.static_config.computed_create_fields.clone()}
                                         config={self.config.clone()}
+                                        permissions={self.static_config.permissions.clone()}
                                         list_view_available={true}
                                         on_list_view={ctx.link().callback(|_| Msg::List)}
                                         on_entity_created={ctx.link().callback(Msg::EntityCreated)}
@@ -296,6 +440,8 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                                         on_link={ctx.link().callback(|link: Option<Scope<CrudCreateView<T>>>|
                                             Msg::ViewLinked(link.map(|link| ViewLink::Create(link))))}
                                         on_tab_selected={ctx.link().callback(|label| Msg::TabSelected(label))}
+                                        on_dirty_changed={ctx.link().callback(Msg::FormDirtyChanged)}
+                                        instance_name={ctx.props().name.clone()}
                                     />
                                 }
                             },
@@ -306,6 +452,7 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                                         children={ctx.props().children.clone()}
                                         custom_fields={self.static_config.custom_update_fields.clone()}
                                         config={self.config.clone()}
+                                        permissions={self.static_config.permissions.clone()}
                                         id={id.clone()}
                                         list_view_available={true}
                                         on_list_view={ctx.link().callback(|_| Msg::List)}
@@ -334,6 +481,8 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                                             Msg::ViewLinked(link.map(|link| ViewLink::Edit(link))))}
                                         on_tab_selected={ctx.link().callback(|label| Msg::TabSelected(label))}
                                         on_entity_action={ctx.link().callback(Msg::CustomEntityAction)}
+                                        on_dirty_changed={ctx.link().callback(Msg::FormDirtyChanged)}
+                                        instance_name={ctx.props().name.clone()}
                                     />
                                 }
                             },
@@ -365,6 +514,15 @@ impl<T: 'static + CrudMainTrait> CrudInstance<T> {
                             None => html! {}
                         }
                     }
+
+                    if self.pending_view.is_some() {
+                        <CrudModal>
+                            <CrudLeaveModal
+                                on_cancel={ctx.link().callback(|_| Msg::LeaveCanceled)}
+                                on_leave={ctx.link().callback(|_| Msg::LeaveApproved)}
+                            />
+                        </CrudModal>
+                    }
                 </div>
             </div>
         }
@@ -395,6 +553,11 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
         let link = ctx.link().clone();
         instance_links_dispatch.reduce_mut(|state| state.save(name, Some(link)));
 
+        // Until the user interacts with any instance, the first one created receives the keyboard shortcuts.
+        let active_instance_dispatch = Dispatch::subscribe(ctx.link().callback(Msg::ActiveInstanceUpdated));
+        let name = ctx.props().name.clone();
+        active_instance_dispatch.reduce_mut(|state| state.activate_if_none(name));
+
         Self {
             instance_dispatch: Dispatch::subscribe(
                 ctx.link().callback(Msg::InstanceConfigStoreUpdated),
@@ -406,6 +569,9 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
             instance_views_store: Default::default(),
             instance_links_dispatch,
             toasts_dispatch: Dispatch::new(),
+            active_instance_dispatch,
+            active: false,
+            root: NodeRef::default(),
 
             view_link: None,
 
@@ -414,6 +580,10 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
             data_provider: CrudRestDataProvider::new(ctx.props().config.api_base_url.clone()),
             entity_to_delete: None,
             parent_id: None,
+
+            form_dirty: false,
+            before_unload_guard: None,
+            pending_view: None,
         }
     }
 
@@ -421,6 +591,9 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
         let name = ctx.props().name.clone();
         self.instance_links_dispatch
             .reduce_mut(|state| state.save(name, None));
+        let name = ctx.props().name.clone();
+        self.active_instance_dispatch
+            .reduce_mut(|state| state.deactivate(&name));
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -429,7 +602,16 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                 self.instance_store = store;
                 match self.instance_store.get(&ctx.props().name) {
                     Some(config) => {
-                        self.config = config;
+                        // The view might have been switched from outside, e.g. by another instance or the router.
+                        if self.form_dirty && config.view != self.config.view {
+                            self.pending_view = Some(config.view.clone());
+                            self.config = CrudInstanceConfig {
+                                view: self.config.view.clone(),
+                                ..config
+                            };
+                        } else {
+                            self.config = config;
+                        }
                         true
                     }
                     None => false,
@@ -512,6 +694,32 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                     self.toasts_dispatch
                         .reduce_mut(|state| state.push_toast(toast));
                 }
+                let failed = action
+                    .entity_results
+                    .iter()
+                    .filter_map(|it| it.result.as_ref().err().map(|reason| (&it.entity, reason)))
+                    .map(|(entity, reason)| format!("{entity}: {reason}"))
+                    .collect::<Vec<_>>();
+                if !failed.is_empty() {
+                    let succeeded = action.entity_results.len() - failed.len();
+                    let message = format!(
+                        "{succeeded} erfolgreich, {} fehlgeschlagen. {}",
+                        failed.len(),
+                        failed.join("; ")
+                    );
+                    self.toasts_dispatch.reduce_mut(|state| {
+                        state.push_toast(Toast {
+                            id: Uuid::new_v4(),
+                            created_at: time::OffsetDateTime::now_utc(),
+                            variant: ToastVariant::Warn,
+                            heading: "Aktion teilweise fehlgeschlagen".to_owned(),
+                            message,
+                            dismissible: true,
+                            automatically_closing: ToastAutomaticallyClosing::No,
+                            close_callback: None,
+                        })
+                    });
+                }
                 if action.reload_data {
                     ctx.link().send_message(Msg::Reload)
                 }
@@ -523,6 +731,10 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                 true
             }
             Msg::Create => {
+                if !self.static_config.permissions.view_allowed(&CrudSimpleView::Create) {
+                    warn!("Not allowed to create entities.");
+                    return false;
+                }
                 self.set_view(CrudView::Create);
                 self.store_config(ctx);
                 true
@@ -690,16 +902,44 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                 false
             }
             Msg::Read(entity) => {
+                let permissions = &self.static_config.permissions;
+                if !permissions.view_allowed(&CrudSimpleView::Read)
+                    || !permissions.entity_allowed(&entity.clone().into(), EntityOperation::Read)
+                {
+                    warn!("Not allowed to read entity {}.", entity.get_id());
+                    return false;
+                }
                 self.set_view(CrudView::Read(entity.get_id()));
                 self.store_config(ctx);
                 true
             }
             Msg::Edit(entity) => {
+                let permissions = &self.static_config.permissions;
+                if !permissions.view_allowed(&CrudSimpleView::Edit)
+                    || !permissions.entity_allowed(&entity, EntityOperation::Edit)
+                {
+                    warn!("Not allowed to edit entity {}.", entity.get_id());
+                    return false;
+                }
                 self.set_view(CrudView::Edit(entity.get_id()));
                 self.store_config(ctx);
                 true
             }
             Msg::Delete(deletable_model) => {
+                let allowed = match &deletable_model {
+                    DeletableModel::Read(entity) => self
+                        .static_config
+                        .permissions
+                        .entity_allowed(&entity.clone().into(), EntityOperation::Delete),
+                    DeletableModel::Update(entity) => self
+                        .static_config
+                        .permissions
+                        .entity_allowed(entity, EntityOperation::Delete),
+                };
+                if !allowed {
+                    warn!("Not allowed to delete entity.");
+                    return false;
+                }
                 self.entity_to_delete = Some(deletable_model);
                 true
             }
@@ -727,6 +967,8 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                 match result {
                     Ok(delete_result) => match delete_result {
                         DeleteResult::Deleted(_amount) => {
+                            // Unsaved changes of a deleted entity can not be saved anymore.
+                            self.set_form_dirty(false);
                             match &self.entity_to_delete {
                                 Some(deletable_model) => match deletable_model {
                                     DeletableModel::Read(read_model) => {
@@ -823,6 +1065,28 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                 self.store_config(ctx);
                 false
             }
+            Msg::HeadersChanged(headers) => {
+                self.config.headers = headers;
+                self.store_config(ctx);
+                false
+            }
+            Msg::HeadersReset => {
+                self.config.headers = ctx.props().config.headers.clone();
+                self.store_config(ctx);
+                false
+            }
+            Msg::ColumnResized((field, width)) => {
+                if let Some((_field, options)) = self
+                    .config
+                    .headers
+                    .iter_mut()
+                    .find(|(it, _options)| it == &field)
+                {
+                    options.width = Some(width);
+                    self.store_config(ctx);
+                }
+                false
+            }
             Msg::SaveInput((field, value)) => {
                 // info!(
                 //     "CrudInstance saving value '{:?}' for field '{:?}'",
@@ -932,6 +1196,53 @@ impl<T: 'static + CrudMainTrait> Component for CrudInstance<T> {
                 }
                 true
             }
+            Msg::FormDirtyChanged(dirty) => {
+                self.set_form_dirty(dirty);
+                false
+            }
+            Msg::ActiveInstanceUpdated(store) => {
+                let active = store.is_active(&ctx.props().name);
+                let changed = self.active != active;
+                self.active = active;
+                changed
+            }
+            Msg::Interacted(target) => {
+                let innermost_instance = target
+                    .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    .and_then(|element| element.closest(".crud-instance").ok().flatten());
+                let is_innermost = match (innermost_instance, self.root.cast::<web_sys::Element>()) {
+                    (Some(instance), Some(root)) => JsValue::from(instance) == JsValue::from(root),
+                    _ => false,
+                };
+                if is_innermost && !self.active {
+                    let name = ctx.props().name.clone();
+                    self.active_instance_dispatch
+                        .reduce_mut(|state| state.activate(name));
+                }
+                false
+            }
+            Msg::LeaveCanceled => {
+                self.pending_view = None;
+                // The view might have been switched in the store. Store the view we stayed in.
+                self.store_config(ctx);
+                true
+            }
+            Msg::LeaveApproved => {
+                self.set_form_dirty(false);
+                // The user decided to give up the changes. They must not be offered for restoration later on.
+                let name = ctx.props().name.clone();
+                let view = self.config.view.clone();
+                Dispatch::<stores::drafts::DraftsStore<T>>::new().reduce_mut(|state| match view {
+                    CrudView::Create => state.remove_create_draft(&name),
+                    CrudView::Edit(id) => state.remove_edit_draft(&name, &id),
+                    CrudView::List | CrudView::Read(_) => {}
+                });
+                if let Some(view) = self.pending_view.take() {
+                    self.set_view(view);
+                    self.store_config(ctx);
+                }
+                true
+            }
         }
     }
 
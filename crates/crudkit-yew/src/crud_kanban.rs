@@ -0,0 +1,494 @@
+use crudkit_condition::{
+    Condition, ConditionClause, ConditionClauseValue, ConditionElement, IntoAllEqualCondition,
+};
+use crudkit_id::{Id, IdField};
+use crudkit_shared::{Order, SaveResult};
+use indexmap::IndexMap;
+use tracing::warn;
+use yew::{html::ChildrenRenderer, prelude::*};
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use crate::{
+    crud_instance::Item,
+    crud_table::RowPermissions,
+    services::crud_rest_data_provider::{CrudRestDataProvider, ReadCount, ReadMany, UpdateOne},
+    types::{custom_field::CustomReadFields, permissions::EntityOperation},
+};
+
+use super::{prelude::*, types::RequestError};
+
+/// A column of a `CrudKanban` board, containing all entities whose grouping field holds `value`.
+#[derive(Debug, Clone)]
+pub struct KanbanColumn {
+    pub label: String,
+    /// Value of the grouping field, set on entities dropped into this column.
+    pub value: Value,
+    /// The same value, as used in conditions when loading the entities of this column.
+    pub condition_value: ConditionClauseValue,
+}
+
+impl PartialEq for KanbanColumn {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && self.value.to_string() == other.value.to_string()
+            && self.condition_value == other.condition_value
+    }
+}
+
+/// Results are tagged with the index of their column and the generation of the column state they were requested for.
+pub enum Msg<T: CrudMainTrait> {
+    CountLoaded((usize, u64, Result<u64, RequestError>)),
+    CardsLoaded((usize, u64, Result<Vec<T::ReadModel>, RequestError>)),
+    LoadMore(usize),
+    Read(T::ReadModel),
+    Edit(T::ReadModel),
+    DragStart((usize, T::ReadModel)),
+    DragEnd,
+    /// Drop the dragged card into the column with the given index.
+    Drop(usize),
+    Moved((usize, usize, Result<SaveResult<T::UpdateModel>, RequestError>)),
+    Reload,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props<T: 'static + CrudMainTrait> {
+    #[prop_or_default]
+    pub children: ChildrenRenderer<Item>,
+    #[prop_or_default]
+    pub custom_fields: CustomReadFields<T>,
+    pub api_base_url: String,
+    pub data_provider: CrudRestDataProvider<T>,
+    /// The field by whose value entities are grouped into columns. Usually a `Select` field.
+    pub group_field: <T::ReadModel as CrudDataTrait>::Field,
+    pub columns: Vec<KanbanColumn>,
+    /// The field shown as the title of a card.
+    pub title_field: <T::ReadModel as CrudDataTrait>::Field,
+    /// Further fields shown on a card.
+    #[prop_or_default]
+    pub card_fields: Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>,
+    #[prop_or_default]
+    pub order_by: IndexMap<<T::ReadModel as CrudDataTrait>::Field, Order>,
+    /// Number of cards loaded at once per column.
+    #[prop_or(20)]
+    pub page_size: u64,
+    #[prop_or(true)]
+    pub read_allowed: bool,
+    #[prop_or(true)]
+    pub edit_allowed: bool,
+    /// Further restricts `read_allowed`, `edit_allowed` and `move_allowed` for individual cards.
+    #[prop_or_default]
+    pub row_permissions: Option<RowPermissions<T::ReadModel>>,
+    /// Cards can only be moved if this is true.
+    #[prop_or(true)]
+    pub move_allowed: bool,
+    #[prop_or_default]
+    pub on_read: Callback<T::ReadModel>,
+    #[prop_or_default]
+    pub on_edit: Callback<T::ReadModel>,
+    /// Called after a card was successfully moved into another column.
+    #[prop_or_default]
+    pub on_moved: Callback<T::UpdateModel>,
+}
+
+impl<T: 'static + CrudMainTrait> Props<T> {
+    fn allows(&self, entity: &T::ReadModel, operation: EntityOperation) -> bool {
+        RowPermissions::allows(
+            self.row_permissions.as_ref(),
+            (self.read_allowed, self.edit_allowed, false),
+            entity,
+            operation,
+        )
+    }
+
+    fn movable(&self, entity: &T::ReadModel) -> bool {
+        self.move_allowed && self.allows(entity, EntityOperation::Edit)
+    }
+}
+
+struct ColumnState<T: CrudMainTrait> {
+    /// Identifies this state. Responses requested for an earlier state of the column are dropped.
+    generation: u64,
+    count: Result<u64, NoData>,
+    cards: Result<Vec<T::ReadModel>, NoData>,
+    loading: bool,
+}
+
+impl<T: CrudMainTrait> ColumnState<T> {
+    fn new(generation: u64) -> Self {
+        Self {
+            generation,
+            count: Err(NoData::NotYetLoaded),
+            cards: Err(NoData::NotYetLoaded),
+            loading: false,
+        }
+    }
+
+    fn loaded(&self) -> usize {
+        self.cards.as_ref().map_or(0, |cards| cards.len())
+    }
+
+    fn has_more(&self) -> bool {
+        match &self.count {
+            Ok(count) => (self.loaded() as u64) < *count,
+            Err(_) => false,
+        }
+    }
+}
+
+/// Displays entities as cards on a board, grouped into columns by the value of a single field.
+/// Every column loads its entities on its own, a chunk at a time.
+/// Dragging a card into another column updates the grouping field of its entity.
+pub struct CrudKanban<T: CrudMainTrait> {
+    columns: Vec<ColumnState<T>>,
+    /// The dragged card and the index of the column it came from.
+    dragged: Option<(usize, T::ReadModel)>,
+    ongoing_move: bool,
+    /// Incremented whenever a column starts over.
+    generation: u64,
+}
+
+impl<T: 'static + CrudMainTrait> CrudKanban<T> {
+    fn column_condition(ctx: &Context<Self>, column: usize) -> Option<Condition> {
+        ctx.props().columns.get(column).map(|it| {
+            Condition::All(vec![ConditionElement::Clause(ConditionClause {
+                column_name: ctx.props().group_field.get_name().to_owned(),
+                operator: crudkit_condition::Operator::Equal,
+                value: it.condition_value.clone(),
+            })])
+        })
+    }
+
+    fn load_count(&self, ctx: &Context<Self>, column: usize) {
+        let generation = match self.columns.get(column) {
+            Some(state) => state.generation,
+            None => return,
+        };
+        let condition = Self::column_condition(ctx, column);
+        let data_provider = ctx.props().data_provider.clone();
+        ctx.link().send_future(async move {
+            Msg::CountLoaded((
+                column,
+                generation,
+                data_provider.read_count(ReadCount { condition }).await,
+            ))
+        });
+    }
+
+    /// Loads the next chunk of cards of a column.
+    fn load_cards(&mut self, ctx: &Context<Self>, column: usize) {
+        let state = match self.columns.get_mut(column) {
+            Some(state) if !state.loading => state,
+            _ => return,
+        };
+        state.loading = true;
+        let generation = state.generation;
+        let skip = state.loaded() as u64;
+        let condition = Self::column_condition(ctx, column);
+        let data_provider = ctx.props().data_provider.clone();
+        let order_by = ctx.props().order_by.clone();
+        let limit = ctx.props().page_size;
+        ctx.link().send_future(async move {
+            Msg::CardsLoaded((
+                column,
+                generation,
+                data_provider
+                    .read_many(ReadMany {
+                        limit: Some(limit),
+                        skip: Some(skip),
+                        order_by: Some(order_by),
+                        condition,
+                    })
+                    .await,
+            ))
+        });
+    }
+
+    /// Discards everything loaded for the given column and loads it again.
+    fn reload_column(&mut self, ctx: &Context<Self>, column: usize) {
+        self.generation += 1;
+        if let Some(state) = self.columns.get_mut(column) {
+            *state = ColumnState::new(self.generation);
+            self.load_count(ctx, column);
+            self.load_cards(ctx, column);
+        }
+    }
+
+    fn reload(&mut self, ctx: &Context<Self>) {
+        self.generation += 1;
+        self.columns = ctx.props().columns.iter().map(|_| ColumnState::new(self.generation)).collect();
+        for column in 0..self.columns.len() {
+            self.load_count(ctx, column);
+            self.load_cards(ctx, column);
+        }
+    }
+
+    fn move_card(&mut self, ctx: &Context<Self>, from: usize, card: T::ReadModel, to: usize) {
+        let group_field_name = ctx.props().group_field.get_name();
+        let group_field = match T::UpdateModel::get_all_fields()
+            .into_iter()
+            .find(|field| field.get_name() == group_field_name)
+        {
+            Some(field) => field,
+            None => {
+                warn!("CrudKanban: Cannot move card, as the UpdateModel has no field named '{group_field_name}'.");
+                return;
+            }
+        };
+        let value = match ctx.props().columns.get(to) {
+            Some(column) => column.value.clone(),
+            None => return,
+        };
+
+        let mut entity: T::UpdateModel = card.into();
+        group_field.set_value(&mut entity, value);
+
+        let condition = <T as CrudMainTrait>::UpdateModelId::fields_iter(&entity.get_id())
+            .map(|field| (field.name().to_owned(), field.to_value()))
+            .into_all_equal_condition();
+        let data_provider = ctx.props().data_provider.clone();
+        self.ongoing_move = true;
+        ctx.link().send_future(async move {
+            Msg::Moved((
+                from,
+                to,
+                data_provider
+                    .update_one(UpdateOne {
+                        entity,
+                        condition: Some(condition),
+                    })
+                    .await,
+            ))
+        });
+    }
+
+    fn render_card(&self, ctx: &Context<Self>, column: usize, card: &T::ReadModel) -> Html {
+        let link = ctx.link();
+        let dragged = card.clone();
+        let read = card.clone();
+        let clicked = card.clone();
+        let read_allowed = ctx.props().allows(card, EntityOperation::Read);
+        let edit_allowed = ctx.props().allows(card, EntityOperation::Edit);
+        let onclick = match (edit_allowed, read_allowed) {
+            (true, _) => Some(link.callback(move |_| Msg::Edit(clicked.clone()))),
+            (false, true) => Some(link.callback(move |_| Msg::Read(clicked.clone()))),
+            (false, false) => None,
+        };
+        html! {
+            <div
+                class={classes!("crud-kanban-card", onclick.is_some().then(|| "interactable"))}
+                draggable={ctx.props().movable(card).then(|| "true")}
+                ondragstart={link.callback(move |_: DragEvent| Msg::DragStart((column, dragged.clone())))}
+                ondragend={link.callback(|_: DragEvent| Msg::DragEnd)}
+                {onclick}
+            >
+                <div class={"crud-kanban-card-title"}>
+                    { self.render_field(ctx, card, &ctx.props().title_field, FieldOptions::default()) }
+                </div>
+                {
+                    ctx.props().card_fields.iter().map(|(field, options)| html! {
+                        <div class={"crud-kanban-card-field"}>
+                            <span class={"crud-kanban-card-field-label"}>{options.display_name.clone()}</span>
+                            { self.render_field(ctx, card, field, FieldOptions { date_time_display: options.date_time_display, formatter: options.formatter.clone(), ..Default::default() }) }
+                        </div>
+                    }).collect::<Html>()
+                }
+                if read_allowed {
+                    <div class={"action-icons"} onclick={|it: MouseEvent| { it.stop_propagation() }}>
+                        <div class={"action-icon"} onclick={link.callback(move |_| Msg::Read(read.clone()))}>
+                            <CrudIcon variant={Bi::Eye}/>
+                        </div>
+                    </div>
+                }
+            </div>
+        }
+    }
+
+    fn render_field(
+        &self,
+        ctx: &Context<Self>,
+        card: &T::ReadModel,
+        field: &<T::ReadModel as CrudDataTrait>::Field,
+        field_options: FieldOptions,
+    ) -> Html {
+        html! {
+            <CrudField<T::ReadModel>
+                children={ctx.props().children.clone()}
+                custom_fields={ctx.props().custom_fields.clone()}
+                api_base_url={ctx.props().api_base_url.clone()}
+                current_view={CrudSimpleView::List}
+                field_type={field.clone()}
+                field_options={field_options}
+                entity={card.clone()}
+                field_mode={FieldMode::Display}
+                value_changed={|_| {}}
+            />
+        }
+    }
+}
+
+impl<T: 'static + CrudMainTrait> Component for CrudKanban<T> {
+    type Message = Msg<T>;
+    type Properties = Props<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut board = Self {
+            columns: vec![],
+            dragged: None,
+            ongoing_move: false,
+            generation: 0,
+        };
+        board.reload(ctx);
+        board
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.data_provider != ctx.props().data_provider
+            || old_props.group_field != ctx.props().group_field
+            || old_props.columns != ctx.props().columns
+            || old_props.order_by != ctx.props().order_by
+        {
+            self.reload(ctx);
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::CountLoaded((column, generation, result)) => match self.columns.get_mut(column) {
+                Some(state) if state.generation == generation => {
+                    state.count = result.map_err(NoData::FetchFailed);
+                    true
+                }
+                _ => false,
+            },
+            Msg::CardsLoaded((column, generation, result)) => match self.columns.get_mut(column) {
+                Some(state) if state.generation == generation => {
+                    state.loading = false;
+                    state.cards = match (std::mem::replace(&mut state.cards, Err(NoData::NotYetLoaded)), result) {
+                        (Ok(mut cards), Ok(more)) => {
+                            cards.extend(more);
+                            Ok(cards)
+                        }
+                        (Err(_), Ok(cards)) => Ok(cards),
+                        // The cards already shown are kept. Loading more can simply be tried again.
+                        (Ok(cards), Err(err)) => {
+                            warn!("CrudKanban: Loading more cards failed: {err}");
+                            Ok(cards)
+                        }
+                        (Err(_), Err(err)) => Err(NoData::FetchFailed(err)),
+                    };
+                    true
+                }
+                _ => false,
+            },
+            Msg::LoadMore(column) => {
+                self.load_cards(ctx, column);
+                true
+            }
+            Msg::Read(card) => {
+                ctx.props().on_read.emit(card);
+                false
+            }
+            Msg::Edit(card) => {
+                ctx.props().on_edit.emit(card);
+                false
+            }
+            Msg::DragStart(dragged) => {
+                self.dragged = Some(dragged);
+                true
+            }
+            Msg::DragEnd => {
+                self.dragged = None;
+                true
+            }
+            Msg::Drop(to) => {
+                if let Some((from, card)) = self.dragged.take() {
+                    if from != to && !self.ongoing_move {
+                        self.move_card(ctx, from, card, to);
+                    }
+                }
+                true
+            }
+            Msg::Moved((from, to, result)) => {
+                self.ongoing_move = false;
+                match result {
+                    Ok(SaveResult::Saved(saved)) => {
+                        ctx.props().on_moved.emit(saved.entity);
+                        self.reload_column(ctx, from);
+                        self.reload_column(ctx, to);
+                    }
+                    Ok(SaveResult::Aborted { reason }) => {
+                        warn!("CrudKanban: Moving the card was aborted: {reason}");
+                    }
+                    Ok(SaveResult::CriticalValidationErrors) => {
+                        warn!("CrudKanban: Moving the card failed due to critical validation errors.");
+                    }
+                    Err(err) => {
+                        warn!("CrudKanban: Moving the card failed: {err}");
+                    }
+                }
+                true
+            }
+            Msg::Reload => {
+                self.reload(ctx);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let dragged_from = self.dragged.as_ref().map(|(from, _card)| *from);
+        html! {
+            <div class={"crud-kanban"}>
+                {
+                    ctx.props().columns.iter().zip(self.columns.iter()).enumerate().map(|(index, (column, state))| {
+                        let is_drop_target = dragged_from.map_or(false, |from| from != index);
+                        html! {
+                            <div
+                                class={classes!("crud-kanban-column", is_drop_target.then(|| "drop-target"))}
+                                ondragover={move |event: DragEvent| if is_drop_target { event.prevent_default() }}
+                                ondrop={ctx.link().callback(move |event: DragEvent| {
+                                    event.prevent_default();
+                                    Msg::Drop(index)
+                                })}
+                            >
+                                <div class={"crud-kanban-column-header"}>
+                                    <span class={"crud-kanban-column-label"}>{column.label.clone()}</span>
+                                    <span class={"crud-kanban-column-count"}>
+                                        {
+                                            match &state.count {
+                                                Ok(count) => count.to_string(),
+                                                Err(_) => "-".to_owned(),
+                                            }
+                                        }
+                                    </span>
+                                </div>
+                                <div class={"crud-kanban-column-body"}>
+                                    {
+                                        match &state.cards {
+                                            Ok(cards) if cards.is_empty() => html! {
+                                                <div class={"no-data"}>{"Keine Daten"}</div>
+                                            },
+                                            Ok(cards) => cards.iter().map(|card| self.render_card(ctx, index, card)).collect::<Html>(),
+                                            Err(NoData::NotYetLoaded) => html! {},
+                                            Err(reason) => html! {
+                                                <div>{format!("Keine Daten verfügbar: {reason:?}")}</div>
+                                            },
+                                        }
+                                    }
+                                    if state.loading {
+                                        <div class={"crud-kanban-loading"}>
+                                            <CrudIcon variant={Bi::HourglassSplit}/>
+                                        </div>
+                                    } else if state.has_more() {
+                                        <CrudBtn name={"Mehr laden"} variant={Variant::Default} onclick={ctx.link().callback(move |_| Msg::LoadMore(index))} />
+                                    }
+                                </div>
+                            </div>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+        }
+    }
+}
@@ -1,34 +1,54 @@
 use std::rc::Rc;
 
 use crudkit_shared::Order;
+use wasm_bindgen::JsCast;
 use yew::{
     html::{ChildrenRenderer, Scope},
     prelude::*,
 };
 use yew_bootstrap_icons::v1_10_3::Bi;
+use yewdux::prelude::*;
 
 use crate::{
     crud_action::ModalGeneration,
     crud_instance::Item,
+    crud_table::{InlineEditor, RowDetails, RowPermissions},
     prelude::*,
     services::crud_rest_data_provider::{CrudRestDataProvider, ReadCount, ReadMany},
+    stores::global_key_up::GlobalKeyUp,
+    types::computed_field,
     types::custom_field::CustomReadFields,
     types::RequestError,
 };
 
+/// Number of rows rendered above and below the visible area when using `ListMode::InfiniteScroll`.
+/// The next chunk of rows is loaded when the end of the loaded rows comes within this distance.
+const OVERSCAN_ROWS: usize = 10;
+
 // TODO: Disable the reset button as long as there is an ongoing request!
 // TODO: Disable the reset button when a reset is going on...
 
 pub enum Msg<T: CrudMainTrait> {
     ComponentCreated,
     PageSelected(u64),
+    PreviousPage,
+    NextPage,
+    GlobalKeyUp(Rc<GlobalKeyUp>),
     ItemCountSelected(u64),
     PageLoaded(Result<Vec<T::ReadModel>, RequestError>),
+    RowsLoaded {
+        generation: u64,
+        replace: bool,
+        limit: u64,
+        result: Result<Vec<T::ReadModel>, RequestError>,
+    },
+    Scrolled,
     CountRead(Result<usize, RequestError>),
     ToggleFilter,
     OrderBy((<T::ReadModel as CrudDataTrait>::Field, OrderByUpdateOptions)),
     Create,
     EntrySelectionChanged(Vec<T::ReadModel>),
+    ToggleExpanded((T::ReadModel, bool)),
     Read(T::ReadModel),
     Edit(T::ReadModel),
     Delete(T::ReadModel),
@@ -46,11 +66,25 @@ pub enum Msg<T: CrudMainTrait> {
             Callback<Result<CrudActionAftermath, CrudActionAftermath>>,
         )>,
     },
+    BatchActionTriggered {
+        action_id: &'static str,
+        action_payload: Option<T::ActionPayload>,
+        action: Callback<(
+            Vec<T::ReadModel>,
+            Option<T::ActionPayload>,
+            Callback<Result<CrudActionAftermath, CrudActionAftermath>>,
+        )>,
+    },
     ActionExecuted {
         action_id: &'static str,
         result: Result<CrudActionAftermath, CrudActionAftermath>,
     },
     EntityActionTriggered((Rc<Box<dyn CrudActionTrait>>, T::ReadModel)),
+    ToggleColumnChooser,
+    HeadersChanged(Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>),
+    HeadersReset,
+    ColumnResized((<T::ReadModel as CrudDataTrait>::Field, u32)),
+    InlineEditSaved,
     Reset,
     Reload,
 }
@@ -62,6 +96,11 @@ pub struct Props<T: CrudMainTrait + 'static> {
     pub data_provider: CrudRestDataProvider<T>,
     pub config: CrudInstanceConfig<T>,
     pub static_config: CrudStaticInstanceConfig<T>,
+    /// The headers as initially configured. The user may choose to show any other field or reset to these.
+    pub default_headers: Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>,
+    pub on_headers_changed: Callback<Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>>,
+    pub on_headers_reset: Callback<()>,
+    pub on_column_resized: Callback<(<T::ReadModel as CrudDataTrait>::Field, u32)>,
     pub on_reset: Callback<()>,
     pub on_create: Callback<()>,
     pub on_read: Callback<T::ReadModel>,
@@ -73,18 +112,81 @@ pub struct Props<T: CrudMainTrait + 'static> {
     pub on_entity_action: Callback<(Rc<Box<dyn CrudActionTrait>>, T::ReadModel)>,
     pub on_global_action: Callback<CrudActionAftermath>,
     pub on_link: Callback<Option<Scope<CrudListView<T>>>>,
+    /// Whether the surrounding instance is the one the user interacts with. Keyboard shortcuts are ignored otherwise.
+    #[prop_or(true)]
+    pub active: bool,
+    /// Whether the surrounding instance shows a modal, e.g. to confirm a deletion.
+    #[prop_or_default]
+    pub modal_open: bool,
 }
 
 pub struct CrudListView<T: 'static + CrudMainTrait> {
+    _global_key_up_dispatch: Dispatch<GlobalKeyUp>,
     data: Result<Rc<Vec<T::ReadModel>>, (NoData, time::OffsetDateTime)>,
     selected: Vec<T::ReadModel>,
+    /// Ids of the expanded rows. Kept while the same page is shown, even if its data is reloaded.
+    expanded: Vec<T::ReadModelId>,
     filter: Option<()>,
     item_count: Result<u64, (NoData, time::OffsetDateTime)>,
     user_wants_to_activate: Vec<String>,
     actions_executing: Vec<&'static str>,
+    column_chooser_open: bool,
+    inline_editor: Option<InlineEditor<T>>,
+
+    // State only used in `ListMode::InfiniteScroll`. `data` then contains all rows loaded so far.
+    /// Incremented whenever the loaded rows are discarded. Rows loaded for an older generation are ignored.
+    rows_generation: u64,
+    loading_rows: bool,
+    all_rows_loaded: bool,
+    scroll_container: NodeRef,
+    scroll_top: f64,
 }
 
 impl<T: CrudMainTrait> CrudListView<T> {
+    /// Builds the editor of inline editable cells. Kept in the component, so that the table is not handed a new editor on every render.
+    fn build_inline_editor(ctx: &Context<CrudListView<T>>) -> Option<InlineEditor<T>> {
+        ctx.props().config.table_options.inline_edit.then(|| {
+            let permissions = ctx.props().static_config.permissions.clone();
+            let children = ctx.props().children.clone();
+            let custom_read_fields = ctx.props().custom_fields.clone();
+            let custom_update_fields = ctx.props().static_config.custom_update_fields.clone();
+            let api_base_url = ctx.props().config.api_base_url.clone();
+            let data_provider = ctx.props().data_provider.clone();
+            let on_saved = ctx.link().callback(|_| Msg::InlineEditSaved);
+            InlineEditor {
+                renderer: Rc::new(
+                    move |entity: &T::ReadModel,
+                          field: &<T::ReadModel as CrudDataTrait>::Field,
+                          options: &HeaderOptions,
+                          on_close: Callback<()>| {
+                        html! {
+                            <CrudInlineEditCell<T>
+                                children={children.clone()}
+                                custom_read_fields={custom_read_fields.clone()}
+                                custom_update_fields={custom_update_fields.clone()}
+                                api_base_url={api_base_url.clone()}
+                                data_provider={data_provider.clone()}
+                                entity={entity.clone()}
+                                field={field.clone()}
+                                header_options={options.clone()}
+                                on_saved={on_saved.clone()}
+                                {on_close}
+                            />
+                        }
+                    },
+                ),
+                editable: Rc::new(
+                    move |entity: &T::ReadModel, field: &<T::ReadModel as CrudDataTrait>::Field| {
+                        permissions.view_allowed(&CrudSimpleView::Edit)
+                            && permissions.field_access(&CrudSimpleView::Edit, field.get_name())
+                                == FieldAccess::Editable
+                            && permissions.entity_allowed(&entity.clone().into(), EntityOperation::Edit)
+                    },
+                ),
+            }
+        })
+    }
+
     fn load_page(&self, ctx: &Context<CrudListView<T>>) {
         let order_by = ctx.props().config.order_by.clone();
         let page = ctx.props().config.page as u64;
@@ -115,6 +217,66 @@ impl<T: CrudMainTrait> CrudListView<T> {
         });
     }
 
+    /// Loads the next chunk of rows. If `replace` is set, all rows loaded so far are fetched again instead.
+    /// Replacing drops the result of any chunk still being loaded, as it would be appended to the replaced rows.
+    fn load_rows(&mut self, ctx: &Context<CrudListView<T>>, replace: bool) {
+        if replace {
+            self.rows_generation += 1;
+        }
+        let loaded = self.get_data().map_or(0, |data| data.len()) as u64;
+        let items_per_page = ctx.props().config.items_per_page;
+        let (skip, limit) = match replace {
+            true => (0, u64::max(loaded, items_per_page)),
+            false => (loaded, items_per_page),
+        };
+        let order_by = ctx.props().config.order_by.clone();
+        let data_provider = ctx.props().data_provider.clone();
+        let generation = self.rows_generation;
+        self.loading_rows = true;
+        ctx.link().send_future(async move {
+            Msg::RowsLoaded {
+                generation,
+                replace,
+                limit,
+                result: data_provider
+                    .read_many(ReadMany {
+                        limit: Some(limit),
+                        skip: Some(skip),
+                        order_by: Some(order_by),
+                        condition: None,
+                    })
+                    .await,
+            }
+        });
+    }
+
+    /// Discards all loaded rows and starts loading from the top.
+    fn reset_rows(&mut self, ctx: &Context<CrudListView<T>>) {
+        self.rows_generation += 1;
+        self.data = Err((NoData::NotYetLoaded, time::OffsetDateTime::now_utc()));
+        self.all_rows_loaded = false;
+        self.scroll_top = 0.0;
+        if let Some(container) = self.scroll_container.cast::<web_sys::Element>() {
+            container.set_scroll_top(0);
+        }
+        self.load_rows(ctx, false);
+    }
+
+    /// Fills the virtual columns of the loaded rows.
+    fn compute_fields(ctx: &Context<CrudListView<T>>, mut rows: Vec<T::ReadModel>) -> Vec<T::ReadModel> {
+        for row in &mut rows {
+            computed_field::apply(&ctx.props().static_config.computed_read_fields, row);
+        }
+        rows
+    }
+
+    fn load_data(&mut self, ctx: &Context<CrudListView<T>>) {
+        match ctx.props().config.list_mode {
+            ListMode::Paginated => self.load_page(ctx),
+            ListMode::InfiniteScroll { .. } => self.load_rows(ctx, true),
+        }
+    }
+
     fn get_data(&self) -> Option<Rc<Vec<T::ReadModel>>> {
         match &self.data {
             Ok(data) => Some(data.clone()),
@@ -138,12 +300,21 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
         ctx.props().on_link.emit(Some(ctx.link().clone()));
         ctx.link().send_future(async move { Msg::ComponentCreated });
         Self {
+            _global_key_up_dispatch: Dispatch::subscribe(ctx.link().callback(Msg::GlobalKeyUp)),
             data: Err((NoData::NotYetLoaded, time::OffsetDateTime::now_utc())),
             selected: vec![],
+            expanded: vec![],
             filter: None,
             item_count: Err((NoData::NotYetLoaded, time::OffsetDateTime::now_utc())),
             user_wants_to_activate: vec![],
             actions_executing: vec![],
+            column_chooser_open: false,
+            inline_editor: Self::build_inline_editor(ctx),
+            rows_generation: 0,
+            loading_rows: false,
+            all_rows_loaded: false,
+            scroll_container: NodeRef::default(),
+            scroll_top: 0.0,
         }
     }
 
@@ -154,7 +325,7 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::ComponentCreated => {
-                self.load_page(ctx);
+                self.load_data(ctx);
                 self.load_count(ctx);
                 false
             }
@@ -163,6 +334,64 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                 //self.data = Err(NoData::NotYetLoaded);
                 false
             }
+            Msg::PreviousPage => {
+                match &ctx.props().config.list_mode {
+                    ListMode::Paginated => {
+                        if ctx.props().config.page > 1 {
+                            ctx.props().on_page_selected.emit(ctx.props().config.page - 1);
+                        }
+                    }
+                    ListMode::InfiniteScroll { viewport_height, .. } => {
+                        if let Some(container) = self.scroll_container.cast::<web_sys::Element>() {
+                            container.set_scroll_top(container.scroll_top() - *viewport_height as i32);
+                        }
+                    }
+                }
+                false
+            }
+            Msg::NextPage => {
+                match (&ctx.props().config.list_mode, &self.item_count) {
+                    (ListMode::Paginated, Ok(count)) => {
+                        let items_per_page = u64::max(ctx.props().config.items_per_page, 1);
+                        let page_count = (count + items_per_page - 1) / items_per_page;
+                        if ctx.props().config.page < page_count {
+                            ctx.props().on_page_selected.emit(ctx.props().config.page + 1);
+                        }
+                    }
+                    (ListMode::Paginated, Err(_)) => {}
+                    (ListMode::InfiniteScroll { viewport_height, .. }, _) => {
+                        if let Some(container) = self.scroll_container.cast::<web_sys::Element>() {
+                            container.set_scroll_top(container.scroll_top() + *viewport_height as i32);
+                        }
+                    }
+                }
+                false
+            }
+            Msg::GlobalKeyUp(state) => {
+                // "N" creates a new entry, as hinted by the underlined letter of the "Neu" button.
+                // Keys typed into any input must not trigger this, neither while one of our or the instances modals is open.
+                if let Some(event) = state.latest_event().filter(|_| ctx.props().active) {
+                    let typing = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+                        .map_or(false, |element| {
+                            element.is_content_editable()
+                                || matches!(
+                                    element.tag_name().as_str(),
+                                    "INPUT" | "TEXTAREA" | "SELECT"
+                                )
+                        });
+                    let modifiers =
+                        event.ctrl_key() || event.alt_key() || event.meta_key() || event.shift_key();
+                    let modal_open = ctx.props().modal_open
+                        || self.column_chooser_open
+                        || !self.user_wants_to_activate.is_empty();
+                    if event.key().eq_ignore_ascii_case("n") && !typing && !modifiers && !modal_open {
+                        ctx.link().send_message(Msg::Create);
+                    }
+                }
+                false
+            }
             Msg::ItemCountSelected(page) => {
                 ctx.props().on_item_count_selected.emit(page);
                 //self.data = Err(NoData::NotYetLoaded);
@@ -170,10 +399,63 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
             }
             Msg::PageLoaded(data) => {
                 self.data = data
-                    .map(Rc::new)
+                    .map(|rows| Rc::new(Self::compute_fields(ctx, rows)))
                     .map_err(|err| (NoData::FetchFailed(err), time::OffsetDateTime::now_utc()));
                 true
             }
+            Msg::RowsLoaded {
+                generation,
+                replace,
+                limit,
+                result,
+            } => {
+                if generation != self.rows_generation {
+                    return false;
+                }
+                self.loading_rows = false;
+                match result {
+                    Ok(rows) => {
+                        self.all_rows_loaded = (rows.len() as u64) < limit;
+                        let rows = Self::compute_fields(ctx, rows);
+                        let rows = match (replace, self.get_data()) {
+                            (false, Some(loaded)) => {
+                                loaded.iter().cloned().chain(rows.into_iter()).collect()
+                            }
+                            _ => rows,
+                        };
+                        self.data = Ok(Rc::new(rows));
+                        // The loaded rows might not yet fill the visible area.
+                        ctx.link().send_message(Msg::Scrolled);
+                    }
+                    Err(err) => {
+                        self.data = Err((NoData::FetchFailed(err), time::OffsetDateTime::now_utc()));
+                    }
+                }
+                true
+            }
+            Msg::Scrolled => {
+                let row_height = match ctx.props().config.list_mode {
+                    ListMode::InfiniteScroll { row_height, .. } => row_height,
+                    ListMode::Paginated => return false,
+                };
+                let container = match self.scroll_container.cast::<web_sys::Element>() {
+                    Some(container) => container,
+                    None => return false,
+                };
+                let scroll_top = container.scroll_top() as f64;
+                let remaining = (container.scroll_height() - container.scroll_top() - container.client_height()) as f64;
+                if !self.loading_rows
+                    && !self.all_rows_loaded
+                    && self.data.is_ok()
+                    && remaining < (row_height as usize * OVERSCAN_ROWS) as f64
+                {
+                    self.load_rows(ctx, false);
+                }
+                let rerender = (scroll_top / row_height as f64).floor()
+                    != (self.scroll_top / row_height as f64).floor();
+                self.scroll_top = scroll_top;
+                rerender
+            }
             Msg::CountRead(data) => {
                 self.item_count = data
                     .map_err(|err| (NoData::FetchFailed(err), time::OffsetDateTime::now_utc()))
@@ -193,6 +475,14 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                 ctx.props().on_create.emit(());
                 false
             }
+            Msg::ToggleExpanded((entity, expanded)) => {
+                let id = entity.get_id();
+                self.expanded.retain(|it| it != &id);
+                if expanded {
+                    self.expanded.push(id);
+                }
+                true
+            }
             Msg::EntrySelectionChanged(selected) => {
                 self.selected = selected;
                 // TODO: Show special ui for multi-selection.
@@ -250,13 +540,41 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                     false
                 }
             }
+            Msg::BatchActionTriggered {
+                action_id,
+                action_payload,
+                action,
+            } => {
+                if let Some(index) = self
+                    .user_wants_to_activate
+                    .iter()
+                    .position(|it| it.as_str() == action_id)
+                {
+                    self.user_wants_to_activate.remove(index);
+                }
+                action.emit((
+                    self.selected.clone(),
+                    action_payload,
+                    ctx.link()
+                        .callback(move |result| Msg::ActionExecuted { action_id, result }),
+                ));
+                if !self.actions_executing.contains(&action_id) {
+                    self.actions_executing.push(action_id);
+                }
+                true
+            }
             Msg::ActionExecuted { action_id, result } => {
                 // We currently handle both the success and the error path in the same way. This might need to be changes in the future.
                 // But the user should always state in which path we are!
-                match result {
-                    Ok(aftermath) => ctx.props().on_global_action.emit(aftermath),
-                    Err(aftermath) => ctx.props().on_global_action.emit(aftermath),
+                let aftermath = match result {
+                    Ok(aftermath) => aftermath,
+                    Err(aftermath) => aftermath,
+                };
+                // Reloaded entities would no longer match the selected ones.
+                if aftermath.reload_data {
+                    self.selected.clear();
                 }
+                ctx.props().on_global_action.emit(aftermath);
                 if let Some(index) = self
                     .actions_executing
                     .iter()
@@ -272,33 +590,284 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                 ctx.props().on_entity_action.emit((action, entity));
                 false
             }
+            Msg::ToggleColumnChooser => {
+                self.column_chooser_open = !self.column_chooser_open;
+                true
+            }
+            Msg::HeadersChanged(headers) => {
+                ctx.props().on_headers_changed.emit(headers);
+                false
+            }
+            Msg::HeadersReset => {
+                ctx.props().on_headers_reset.emit(());
+                false
+            }
+            Msg::ColumnResized(resized) => {
+                ctx.props().on_column_resized.emit(resized);
+                false
+            }
+            Msg::InlineEditSaved => {
+                self.load_data(ctx);
+                false
+            }
             Msg::Reload => {
-                self.load_page(ctx);
+                self.load_data(ctx);
                 self.load_count(ctx);
                 false
             }
         }
     }
 
-    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
-        self.load_page(ctx);
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.config.page != ctx.props().config.page
+            || old_props.config.items_per_page != ctx.props().config.items_per_page
+        {
+            self.expanded.clear();
+        }
+        if old_props.config.table_options.inline_edit != ctx.props().config.table_options.inline_edit
+            || old_props.config.api_base_url != ctx.props().config.api_base_url
+            || old_props.static_config != ctx.props().static_config
+            || old_props.children != ctx.props().children
+            || old_props.custom_fields != ctx.props().custom_fields
+            || old_props.data_provider != ctx.props().data_provider
+        {
+            self.inline_editor = Self::build_inline_editor(ctx);
+        }
+        match ctx.props().config.list_mode {
+            ListMode::Paginated => self.load_page(ctx),
+            ListMode::InfiniteScroll { .. } => {
+                // Rows stay loaded unless the data they represent changes.
+                if old_props.config.order_by != ctx.props().config.order_by
+                    || old_props.config.list_mode != ctx.props().config.list_mode
+                    || old_props.data_provider != ctx.props().data_provider
+                {
+                    self.reset_rows(ctx);
+                }
+            }
+        }
         true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let permissions = &ctx.props().static_config.permissions;
+        let headers = ctx
+            .props()
+            .config
+            .headers
+            .iter()
+            .filter(|(field, _options)| {
+                permissions.field_access(&CrudSimpleView::List, field.get_name()) != FieldAccess::Hidden
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        let excluded_fields = T::ReadModel::get_all_fields()
+            .into_iter()
+            .filter(|field| {
+                permissions.field_access(&CrudSimpleView::List, field.get_name()) == FieldAccess::Hidden
+            })
+            .collect::<Vec<_>>();
+        let row_permissions = {
+            let permissions = permissions.clone();
+            RowPermissions {
+                allowed: Rc::new(move |entity: &T::ReadModel, operation| {
+                    permissions.entity_allowed(&entity.clone().into(), operation)
+                }),
+            }
+        };
+        // Deleting has no view of its own. It is offered if the policy allows deleting any of the loaded rows.
+        // Every row is checked on its own through `row_permissions`.
+        let delete_allowed = self.get_data().map_or(false, |rows| {
+            rows.iter()
+                .any(|entity| permissions.entity_allowed(&entity.clone().into(), EntityOperation::Delete))
+        });
+
+        // Expanded rows would break the fixed row height the windowing of `ListMode::InfiniteScroll` relies on.
+        let details_available = matches!(ctx.props().config.list_mode, ListMode::Paginated);
+        let row_details = ctx.props().static_config.row_details.clone().filter(|_| details_available).or_else(|| {
+            match &ctx.props().config.detail_elements {
+                _ if !details_available => None,
+                DetailElements::None => None,
+                DetailElements::Custom(elements) => {
+                    let children = ctx.props().children.clone();
+                    let custom_fields = ctx.props().custom_fields.clone();
+                    let api_base_url = ctx.props().config.api_base_url.clone();
+                    let elements = elements.clone();
+                    Some(RowDetails {
+                        renderer: Rc::new(move |entity: &T::ReadModel| {
+                            html! {
+                                <CrudFields<T::ReadModel>
+                                    children={children.clone()}
+                                    custom_fields={custom_fields.clone()}
+                                    api_base_url={api_base_url.clone()}
+                                    elements={elements.clone()}
+                                    entity={entity.clone()}
+                                    mode={FieldMode::Readable}
+                                    current_view={CrudSimpleView::Read}
+                                    value_changed={|_| {}}
+                                    on_tab_selection={|_| {}}
+                                />
+                            }
+                        }),
+                    })
+                }
+            }
+        });
+        let expanded = match (&row_details, self.get_data()) {
+            (Some(_), Some(data)) => data
+                .iter()
+                .filter(|entity| self.expanded.contains(&entity.get_id()))
+                .cloned()
+                .collect::<Vec<_>>(),
+            _ => vec![],
+        };
+
+        // In infinite scroll mode, only the rows in (and around) the visible area are passed to the table.
+        let (data, selectable, spacer_before, spacer_after) = match (&ctx.props().config.list_mode, self.get_data()) {
+            (ListMode::InfiniteScroll { row_height, viewport_height }, Some(rows)) => {
+                let first = usize::min(
+                    (self.scroll_top / *row_height as f64) as usize,
+                    rows.len(),
+                )
+                .saturating_sub(OVERSCAN_ROWS);
+                let last = usize::min(
+                    first + (*viewport_height / *row_height) as usize + 1 + 2 * OVERSCAN_ROWS,
+                    rows.len(),
+                );
+                (
+                    Some(Rc::new(rows[first..last].to_vec())),
+                    Some(rows.clone()),
+                    first as u32 * row_height,
+                    (rows.len() - last) as u32 * row_height,
+                )
+            }
+            (_, data) => (data, None, 0, 0),
+        };
+
+        let ordered_headers = headers
+            .iter()
+            .map(|(field, options)| (field.clone(), options.clone(), ctx.props().config.order_by.get(field).cloned()))
+            .collect::<Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions, Option<Order>)>>();
+
+        let table = match &ctx.props().config.list_presentation {
+            ListPresentation::Table => html! {
+                <CrudTable<T::ReadModel>
+                    children={ctx.props().children.clone()}
+                    custom_fields={ctx.props().custom_fields.clone()}
+                    api_base_url={ctx.props().config.api_base_url.clone()}
+                    data={data}
+                    selectable={selectable}
+                    {spacer_before}
+                    {spacer_after}
+                    no_data={self.get_data_error()}
+                    headers={ordered_headers.clone()}
+                    on_order_by={ctx.link().callback(Msg::OrderBy)}
+                    on_column_resized={ctx.link().callback(Msg::ColumnResized)}
+                    options={ctx.props().config.table_options.clone()}
+                    inline_editor={self.inline_editor.clone()}
+                    {row_details}
+                    {expanded}
+                    on_toggle_expanded={ctx.link().callback(Msg::ToggleExpanded)}
+                    read_allowed={permissions.view_allowed(&CrudSimpleView::Read)}
+                    edit_allowed={permissions.view_allowed(&CrudSimpleView::Edit)}
+                    {delete_allowed}
+                    row_permissions={row_permissions}
+                    selected={self.selected.clone()}
+                    on_selection={ctx.link().callback(Msg::EntrySelectionChanged)}
+                    on_read={ctx.link().callback(Msg::Read)}
+                    on_edit={ctx.link().callback(Msg::Edit)}
+                    on_delete={ctx.link().callback(Msg::Delete)}
+                    on_previous_page={ctx.link().callback(|_| Msg::PreviousPage)}
+                    on_next_page={ctx.link().callback(|_| Msg::NextPage)}
+                    additional_item_actions={vec![]}
+                    on_additional_item_action={ctx.link().callback(Msg::EntityActionTriggered)}
+                />
+            },
+            ListPresentation::Cards(layout) => html! {
+                <CrudCardGrid<T::ReadModel>
+                    children={ctx.props().children.clone()}
+                    custom_fields={ctx.props().custom_fields.clone()}
+                    api_base_url={ctx.props().config.api_base_url.clone()}
+                    data={self.get_data()}
+                    no_data={self.get_data_error()}
+                    layout={layout.clone()}
+                    headers={ordered_headers.clone()}
+                    on_order_by={ctx.link().callback(Msg::OrderBy)}
+                    read_allowed={permissions.view_allowed(&CrudSimpleView::Read)}
+                    edit_allowed={permissions.view_allowed(&CrudSimpleView::Edit)}
+                    {delete_allowed}
+                    row_permissions={row_permissions.clone()}
+                    selected={self.selected.clone()}
+                    on_selection={ctx.link().callback(Msg::EntrySelectionChanged)}
+                    on_read={ctx.link().callback(Msg::Read)}
+                    on_edit={ctx.link().callback(Msg::Edit)}
+                    on_delete={ctx.link().callback(Msg::Delete)}
+                    additional_item_actions={vec![]}
+                    on_additional_item_action={ctx.link().callback(Msg::EntityActionTriggered)}
+                />
+            },
+            ListPresentation::Calendar(layout) => html! {
+                <CrudCalendar<T>
+                    children={ctx.props().children.clone()}
+                    custom_fields={ctx.props().custom_fields.clone()}
+                    api_base_url={ctx.props().config.api_base_url.clone()}
+                    data_provider={ctx.props().data_provider.clone()}
+                    layout={layout.clone()}
+                    read_allowed={permissions.view_allowed(&CrudSimpleView::Read)}
+                    edit_allowed={permissions.view_allowed(&CrudSimpleView::Edit)}
+                    move_allowed={permissions.view_allowed(&CrudSimpleView::Edit)}
+                    row_permissions={row_permissions.clone()}
+                    on_read={ctx.link().callback(Msg::Read)}
+                    on_edit={ctx.link().callback(Msg::Edit)}
+                />
+            },
+            ListPresentation::Kanban(layout) => html! {
+                <CrudKanban<T>
+                    children={ctx.props().children.clone()}
+                    custom_fields={ctx.props().custom_fields.clone()}
+                    api_base_url={ctx.props().config.api_base_url.clone()}
+                    data_provider={ctx.props().data_provider.clone()}
+                    group_field={layout.group.clone()}
+                    columns={ctx.props().static_config.kanban_columns.clone()}
+                    title_field={layout.title.clone()}
+                    card_fields={
+                        layout.fields.iter()
+                            .filter(|field| permissions.field_access(&CrudSimpleView::List, field.get_name()) != FieldAccess::Hidden)
+                            .map(|field| {
+                                let options = headers.iter()
+                                    .find(|(it, _options)| it == field)
+                                    .map(|(_field, options)| options.clone())
+                                    .unwrap_or_else(|| HeaderOptions { display_name: field.get_name().to_owned(), ..Default::default() });
+                                (field.clone(), options)
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    order_by={ctx.props().config.order_by.clone()}
+                    read_allowed={permissions.view_allowed(&CrudSimpleView::Read)}
+                    edit_allowed={permissions.view_allowed(&CrudSimpleView::Edit)}
+                    move_allowed={permissions.view_allowed(&CrudSimpleView::Edit)}
+                    row_permissions={row_permissions.clone()}
+                    on_read={ctx.link().callback(Msg::Read)}
+                    on_edit={ctx.link().callback(Msg::Edit)}
+                />
+            },
+        };
+
         html! {
             <>
                 <div class={"crud-row crud-nav"}>
                     <div class={"crud-col"}>
                         <CrudBtnWrapper>
-                            <CrudBtn name={""} variant={Variant::Success} icon={Bi::PlusCircle} onclick={ctx.link().callback(|_| Msg::Create)}>
-                                <CrudBtnName>
-                                    <span style="text-decoration: underline">{"N"}</span>{"eu"}
-                                </CrudBtnName>
-                            </CrudBtn>
+                            if permissions.view_allowed(&CrudSimpleView::Create) {
+                                <CrudBtn name={""} variant={Variant::Success} icon={Bi::PlusCircle} onclick={ctx.link().callback(|_| Msg::Create)}>
+                                    <CrudBtnName>
+                                        <span style="text-decoration: underline">{"N"}</span>{"eu"}
+                                    </CrudBtnName>
+                                </CrudBtn>
+                            }
 
                             {
                                 ctx.props().static_config.actions.iter()
+                                    .filter(|action| permissions.action_allowed(action.id()))
                                     .map(|action| match action {
                                         CrudAction::Custom {id, name, icon, variant, action, modal} => {
                                             let action_id: &str = (&id).clone();
@@ -336,6 +905,8 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                                                 }
                                             }
                                         }
+                                        // Batch actions are part of the selection toolbar.
+                                        CrudAction::Batch { .. } => html! {},
                                     })
                                     .collect::<Html>()
                             }
@@ -344,6 +915,11 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
 
                     <div class={"crud-col crud-col-flex-end"}>
                         <CrudBtnWrapper>
+                            <CrudBtn name={""} variant={Variant::Default} icon={Bi::LayoutThreeColumns} onclick={ctx.link().callback(|_| Msg::ToggleColumnChooser)}>
+                                <CrudBtnName>
+                                    {"Spalten"}
+                                </CrudBtnName>
+                            </CrudBtn>
                             <CrudBtn name={""} variant={Variant::Default} icon={Bi::ArrowRepeat} disabled={false} onclick={ctx.link().callback(|_| Msg::Reset)}>
                                 <CrudBtnName>
                                     {"Reset"}
@@ -363,35 +939,90 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                     </div>
                 </div>
 
-                <CrudTable<T::ReadModel>
-                    children={ctx.props().children.clone()}
-                    custom_fields={ctx.props().custom_fields.clone()}
-                    api_base_url={ctx.props().config.api_base_url.clone()}
-                    data={self.get_data()}
-                    no_data={self.get_data_error()}
-                    headers={ctx.props().config.headers.iter()
-                        .map(|(field, options)| (field.clone(), options.clone(), ctx.props().config.order_by.get(field).cloned()))
-                        .collect::<Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions, Option<Order>)>>()}
-                    on_order_by={ctx.link().callback(Msg::OrderBy)}
-                    read_allowed={true}
-                    edit_allowed={true}
-                    delete_allowed={true}
-                    selected={self.selected.clone()}
-                    on_selection={ctx.link().callback(Msg::EntrySelectionChanged)}
-                    on_read={ctx.link().callback(Msg::Read)}
-                    on_edit={ctx.link().callback(Msg::Edit)}
-                    on_delete={ctx.link().callback(Msg::Delete)}
-                    additional_item_actions={vec![]}
-                    on_additional_item_action={ctx.link().callback(Msg::EntityActionTriggered)}
-                />
+                if self.column_chooser_open {
+                    <CrudModal>
+                        <CrudColumnChooser<T::ReadModel>
+                            headers={ctx.props().config.headers.clone()}
+                            default_headers={ctx.props().default_headers.clone()}
+                            excluded={excluded_fields}
+                            on_change={ctx.link().callback(Msg::HeadersChanged)}
+                            on_reset={ctx.link().callback(|_| Msg::HeadersReset)}
+                            on_close={ctx.link().callback(|_| Msg::ToggleColumnChooser)}
+                        />
+                    </CrudModal>
+                }
+
+                {
+                    match &ctx.props().config.list_mode {
+                        ListMode::Paginated => table,
+                        ListMode::InfiniteScroll { viewport_height, .. } => html! {
+                            <div
+                                class={"crud-infinite-scroll"}
+                                style={format!("height: {viewport_height}px; overflow-y: auto;")}
+                                ref={self.scroll_container.clone()}
+                                onscroll={ctx.link().callback(|_| Msg::Scrolled)}
+                            >
+                                {table}
+                            </div>
+                        },
+                    }
+                }
 
                 {
                     match self.selected.len() {
                         0 => html! {},
                         num_selected => html! {
-                            <div class={"multiselect-actions"}>
-                                <div>
-                                    { num_selected } {"servers selected"}
+                            <div class={"crud-row multiselect-actions"}>
+                                <div class={"crud-col"}>
+                                    <CrudBtnWrapper>
+                                        <span class={"multiselect-count"}>
+                                            { num_selected } {" ausgewählt"}
+                                        </span>
+                                        {
+                                            ctx.props().static_config.actions.iter()
+                                                .filter(|action| permissions.action_allowed(action.id()))
+                                                .map(|action| match action {
+                                                    CrudAction::Batch {id, name, icon, variant, action, modal} => {
+                                                        let action_id: &str = (&id).clone();
+                                                        let action = action.clone();
+
+                                                        if let Some(modal) = modal {
+                                                            html! {
+                                                                <>
+                                                                <CrudBtn
+                                                                    name={name.clone()}
+                                                                    variant={variant.clone()}
+                                                                    icon={icon.clone()}
+                                                                    disabled={self.actions_executing.contains(&id)}
+                                                                    onclick={ctx.link().callback(move |_| Msg::ActionInitialized { action_id }) }
+                                                                />
+                                                                if self.user_wants_to_activate.iter().any(|it| it.as_str() == action_id) {
+                                                                    <CrudModal>
+                                                                        {{ modal(ModalGeneration {
+                                                                            cancel: ctx.link().callback(move |_| Msg::ActionCancelled { action_id }),
+                                                                            execute: ctx.link().callback(move |action_payload| Msg::BatchActionTriggered { action_id, action_payload, action: action.clone() }),
+                                                                        }) }}
+                                                                    </CrudModal>
+                                                                }
+                                                                </>
+                                                            }
+                                                        } else {
+                                                            html! {
+                                                                <CrudBtn
+                                                                    name={name.clone()}
+                                                                    variant={variant.clone()}
+                                                                    icon={icon.clone()}
+                                                                    disabled={self.actions_executing.contains(&id)}
+                                                                    onclick={ctx.link().callback(move |_| Msg::BatchActionTriggered { action_id, action_payload: None, action: action.clone() }) }
+                                                                />
+                                                            }
+                                                        }
+                                                    }
+                                                    CrudAction::Custom { .. } => html! {},
+                                                })
+                                                .collect::<Html>()
+                                        }
+                                    </CrudBtnWrapper>
                                 </div>
                             </div>
                         },
@@ -399,8 +1030,15 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                 }
 
                 {
-                    match &self.item_count {
-                        Ok(count) => html! {
+                    match (&self.item_count, &ctx.props().config.list_mode) {
+                        // The calendar pages through time and the board loads its columns on its own.
+                        _ if matches!(ctx.props().config.list_presentation, ListPresentation::Calendar(_) | ListPresentation::Kanban(_)) => html! {},
+                        (Ok(count), ListMode::InfiniteScroll { .. }) => html! {
+                            <div class={"crud-infinite-scroll-status"}>
+                                {format!("{} von {} Einträgen geladen", self.get_data().map_or(0, |data| data.len()), count)}
+                            </div>
+                        },
+                        (Ok(count), ListMode::Paginated) => html! {
                             <CrudPagination
                                 current_page={ctx.props().config.page}
                                 item_count={*count}
@@ -409,7 +1047,7 @@ impl<T: 'static + CrudMainTrait> Component for CrudListView<T> {
                                 on_item_count_select={ctx.link().callback(|page| Msg::ItemCountSelected(page))}
                             />
                         },
-                        Err((reason, since)) => if (time::OffsetDateTime::now_utc() - *since).whole_seconds() > 5 {
+                        (Err((reason, since)), _) => if (time::OffsetDateTime::now_utc() - *since).whole_seconds() > 5 {
                             html! {
                                 <div>{format!("Keine Daten verfügbar: {reason:?}")}</div>
                             }
@@ -1,4 +1,4 @@
-use time::format_description::well_known::Rfc3339;
+use time::{format_description::well_known::Rfc3339, macros::format_description};
 use tracing::info;
 use yew::prelude::*;
 
@@ -105,6 +105,23 @@ pub struct CrudOffsetDatetime {
     in_focus: bool,
 }
 
+impl CrudOffsetDatetime {
+    /// Only the parts of the value relevant to the input type are shown.
+    fn format_value(&self, ctx: &Context<Self>) -> String {
+        self.value
+            .as_ref()
+            .map(|it| {
+                match ctx.props().input_type {
+                    Type::Date => it.format(format_description!("[day].[month].[year]")),
+                    Type::Time => it.format(format_description!("[hour]:[minute]")),
+                    Type::DateTime => it.format(&Rfc3339),
+                }
+                .unwrap()
+            })
+            .unwrap_or_default()
+    }
+}
+
 impl Component for CrudOffsetDatetime {
     type Message = Msg;
     type Properties = Props;
@@ -243,10 +260,11 @@ impl Component for CrudOffsetDatetime {
             }
         }
 
-        fn time_selector(this: &CrudOffsetDatetime, _ctx: &Context<CrudOffsetDatetime>) -> Html {
+        fn time_selector(this: &CrudOffsetDatetime, ctx: &Context<CrudOffsetDatetime>) -> Html {
             html! {
                 <CrudOffsetDatetimeTimeSelector
                     value={this.value.clone().unwrap_or_else(|| time::OffsetDateTime::now_utc())}
+                    onchange={ctx.link().callback(Msg::UpdateValue)}
                 />
             }
         }
@@ -262,7 +280,7 @@ impl Component for CrudOffsetDatetime {
                     onfocusout={ctx.link().callback(|_| Msg::SetFocus(false))}
                     onkeydown={ctx.link().callback(|event| Msg::KeyDown(event))}
                     placeholder={ctx.props().placeholder.clone()}
-                    value={self.value.as_ref().map(|it| it.format(&Rfc3339).unwrap()).unwrap_or_default()}
+                    value={self.format_value(ctx)}
                     tabindex={"0"}
                 />
                 <div class={"datetime-dropdown-menu-ref"}>
@@ -1,30 +1,70 @@
-use time::format_description::well_known::Rfc3339;
 use yew::prelude::*;
 
+/// Only every fifth minute is offered for selection.
+const MINUTE_STEP: usize = 5;
+
+pub enum Msg {
+    SelectHour(u8),
+    SelectMinute(u8),
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub value: time::OffsetDateTime,
+    pub onchange: Callback<time::OffsetDateTime>,
 }
 
 pub struct CrudOffsetDatetimeTimeSelector {}
 
 impl Component for CrudOffsetDatetimeTimeSelector {
-    type Message = ();
+    type Message = Msg;
     type Properties = Props;
 
     fn create(_ctx: &Context<Self>) -> Self {
         Self {}
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let value = ctx.props().value;
+        let changed = match msg {
+            Msg::SelectHour(hour) => value.replace_hour(hour),
+            Msg::SelectMinute(minute) => value.replace_minute(minute),
+        };
+        if let Ok(changed) = changed {
+            ctx.props().onchange.emit(changed);
+        }
         false
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let hour = ctx.props().value.hour();
+        let minute = ctx.props().value.minute();
         html! {
             <div class={"time-selector"}>
-                {"TimeSelector"}
-                {ctx.props().value.format(&Rfc3339).unwrap()}
+                <div class={"time-selector-hours"}>
+                    {
+                        (0..24u8).map(|it| html! {
+                            <div
+                                class={classes!("time-selector-item", (it == hour).then(|| "selected"))}
+                                onclick={ctx.link().callback(move |_| Msg::SelectHour(it))}
+                            >
+                                {format!("{it:02}")}
+                            </div>
+                        }).collect::<Html>()
+                    }
+                </div>
+                <div class={"time-selector-minutes"}>
+                    {
+                        (0..60u8).step_by(MINUTE_STEP).map(|it| html! {
+                            <div
+                                class={classes!("time-selector-item", (it == minute).then(|| "selected"))}
+                                onclick={ctx.link().callback(move |_| Msg::SelectMinute(it))}
+                            >
+                                {format!("{it:02}")}
+                            </div>
+                        }).collect::<Html>()
+                    }
+                </div>
             </div>
         }
     }
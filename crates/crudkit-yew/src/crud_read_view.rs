@@ -23,6 +23,7 @@ pub struct Props<T: CrudMainTrait> {
     pub custom_fields: CustomUpdateFields<T>,
     pub data_provider: CrudRestDataProvider<T>,
     pub config: CrudInstanceConfig<T>,
+    pub permissions: PermissionPolicy<T>,
     pub id: T::ReadModelId,
     pub list_view_available: bool,
     pub on_list_view: Callback<()>,
@@ -96,7 +97,7 @@ impl<T: 'static + CrudMainTrait> Component for CrudReadView<T> {
                                     api_base_url={ctx.props().config.api_base_url.clone()}
                                     children={ctx.props().children.clone()}
                                     custom_fields={ctx.props().custom_fields.clone()}
-                                    elements={ctx.props().config.elements.clone()}
+                                    elements={ctx.props().permissions.restrict_elements(&CrudSimpleView::Read, &ctx.props().config.elements)}
                                     entity={entity.clone()}
                                     mode={FieldMode::Readable}
                                     current_view={CrudSimpleView::Read}
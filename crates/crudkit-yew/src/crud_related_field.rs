@@ -90,11 +90,15 @@ impl<P: 'static + CrudMainTrait, T: 'static + CrudMainTrait> CrudRelatedField<P,
 fn value_as_u32_vec(value: &Value) -> Vec<u32> {
     match value {
         Value::String(_) => panic!("'String' unsupported"),
+        Value::OptionalString(_) => panic!("'OptionalString' unsupported"),
         Value::Text(_) => panic!("'Text' unsupported"),
+        Value::OptionalText(_) => panic!("'OptionalText' unsupported"),
         Value::Json(_) => panic!("'Json' unsupported"),
         Value::OptionalJson(_) => panic!("'OptionalJson' unsupported"),
         Value::UuidV4(_) => panic!("'UuidV4' unsupported"),
+        Value::OptionalUuidV4(_) => panic!("'OptionalUuidV4' unsupported"),
         Value::UuidV7(_) => panic!("'UuidV7' unsupported"),
+        Value::OptionalUuidV7(_) => panic!("'OptionalUuidV7' unsupported"),
         Value::U32(u32) => vec![*u32],
         Value::OptionalU32(optional_u32) => match optional_u32 {
             Some(u32) => vec![*u32],
@@ -105,16 +109,31 @@ fn value_as_u32_vec(value: &Value) -> Vec<u32> {
         Value::OptionalI32(_) => panic!("'OptionalI32' unsupported"),
         Value::OptionalI64(_) => panic!("'OptionalI64' unsupported"),
         Value::F32(_) => panic!("'F32' unsupported"),
+        Value::F64(_) => panic!("'F64' unsupported"),
+        Value::OptionalF32(_) => panic!("'OptionalF32' unsupported"),
+        Value::OptionalF64(_) => panic!("'OptionalF64' unsupported"),
+        Value::Decimal(_) => panic!("'Decimal' unsupported"),
+        Value::OptionalDecimal(_) => panic!("'OptionalDecimal' unsupported"),
         Value::Bool(_) => panic!("'Bool' unsupported"),
         Value::ValidationStatus(_) => panic!("'ValidationStatus' unsupported"),
         Value::PrimitiveDateTime(_) => panic!("PrimitiveDateTime' unsupported"),
         Value::OffsetDateTime(_) => panic!("OffsetDateTime' unsupported"),
         Value::OptionalPrimitiveDateTime(_) => panic!("'OptionalPrimitiveDateTime' unsupported"),
         Value::OptionalOffsetDateTime(_) => panic!("'OptionalOffsetDateTime' unsupported"),
+        Value::Date(_) => panic!("'Date' unsupported"),
+        Value::OptionalDate(_) => panic!("'OptionalDate' unsupported"),
+        Value::Time(_) => panic!("'Time' unsupported"),
+        Value::OptionalTime(_) => panic!("'OptionalTime' unsupported"),
+        Value::Duration(_) => panic!("'Duration' unsupported"),
+        Value::OptionalDuration(_) => panic!("'OptionalDuration' unsupported"),
         Value::OneToOneRelation(some_u32) => match some_u32 {
             Some(u32) => vec![*u32],
             None => vec![],
         },
+        Value::StringVec(_) => panic!("'StringVec' unsupported"),
+        Value::I32Vec(_) => panic!("'I32Vec' unsupported"),
+        Value::I64Vec(_) => panic!("'I64Vec' unsupported"),
+        Value::UuidVec(_) => panic!("'UuidVec' unsupported"),
         Value::NestedTable(_) => panic!("'NestedTable' unsupported"),
         Value::Custom(_) => panic!("'Custom' unsupported"),
         Value::Select(_) => panic!("'Select' unsupported"),
@@ -127,11 +146,15 @@ fn value_as_u32_vec(value: &Value) -> Vec<u32> {
 fn value_as_u32(value: &Value) -> Option<u32> {
     match value {
         Value::String(_) => panic!("'String' unsupported"),
+        Value::OptionalString(_) => panic!("'OptionalString' unsupported"),
         Value::Text(_) => panic!("'Text' unsupported"),
+        Value::OptionalText(_) => panic!("'OptionalText' unsupported"),
         Value::Json(_) => panic!("'Json' unsupported"),
         Value::OptionalJson(_) => panic!("'OptionalJson' unsupported"),
         Value::UuidV4(_) => panic!("'UuidV4' unsupported"),
+        Value::OptionalUuidV4(_) => panic!("'OptionalUuidV4' unsupported"),
         Value::UuidV7(_) => panic!("'UuidV7' unsupported"),
+        Value::OptionalUuidV7(_) => panic!("'OptionalUuidV7' unsupported"),
         Value::U32(u32) => Some(*u32),
         Value::OptionalU32(optional_u32) => match optional_u32 {
             Some(u32) => Some(*u32),
@@ -142,16 +165,31 @@ fn value_as_u32(value: &Value) -> Option<u32> {
         Value::OptionalI32(_) => panic!("'OptionalI32' unsupported"),
         Value::OptionalI64(_) => panic!("'OptionalI64' unsupported"),
         Value::F32(_) => panic!("'F32' unsupported"),
+        Value::F64(_) => panic!("'F64' unsupported"),
+        Value::OptionalF32(_) => panic!("'OptionalF32' unsupported"),
+        Value::OptionalF64(_) => panic!("'OptionalF64' unsupported"),
+        Value::Decimal(_) => panic!("'Decimal' unsupported"),
+        Value::OptionalDecimal(_) => panic!("'OptionalDecimal' unsupported"),
         Value::Bool(_) => panic!("'Bool' unsupported"),
         Value::ValidationStatus(_) => panic!("'ValidationStatus' unsupported"),
         Value::PrimitiveDateTime(_) => panic!("PrimitiveDateTime' unsupported"),
         Value::OffsetDateTime(_) => panic!("OffsetDateTime' unsupported"),
         Value::OptionalPrimitiveDateTime(_) => panic!("'OptionalPrimitiveDateTime' unsupported"),
         Value::OptionalOffsetDateTime(_) => panic!("'OptionalOffsetDateTime' unsupported"),
+        Value::Date(_) => panic!("'Date' unsupported"),
+        Value::OptionalDate(_) => panic!("'OptionalDate' unsupported"),
+        Value::Time(_) => panic!("'Time' unsupported"),
+        Value::OptionalTime(_) => panic!("'OptionalTime' unsupported"),
+        Value::Duration(_) => panic!("'Duration' unsupported"),
+        Value::OptionalDuration(_) => panic!("'OptionalDuration' unsupported"),
         Value::OneToOneRelation(some_u32) => match some_u32 {
             Some(u32) => Some(*u32),
             None => None,
         },
+        Value::StringVec(_) => panic!("'StringVec' unsupported"),
+        Value::I32Vec(_) => panic!("'I32Vec' unsupported"),
+        Value::I64Vec(_) => panic!("'I64Vec' unsupported"),
+        Value::UuidVec(_) => panic!("'UuidVec' unsupported"),
         Value::NestedTable(_) => panic!("'NestedTable' unsupported"),
         Value::Custom(_) => panic!("'Custom' unsupported"),
         Value::Select(_) => panic!("'Select' unsupported"),
@@ -0,0 +1,180 @@
+use std::{collections::HashMap, marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+use yew_bootstrap_icons::v1_10_3::Bi;
+use yewdux::prelude::Dispatch;
+
+use super::prelude::*;
+use crate::stores::global_key_up::GlobalKeyUp;
+
+/// A field whose value was changed by the user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange<T: CrudDataTrait> {
+    pub field: T::Field,
+    pub label: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// All fields whose value differs between `before` and `after`, in the order of the layout.
+/// Fields are labeled as in the layout. Fields not part of the layout are labeled by their name.
+pub fn field_changes<T: CrudDataTrait>(elements: &[Elem<T>], before: &T, after: &T) -> Vec<FieldChange<T>> {
+    let mut labels = Vec::new();
+    collect_labels(elements, &mut labels);
+    let mut fields = labels.iter().map(|(field, _label)| field.clone()).collect::<Vec<_>>();
+    for field in T::get_all_fields() {
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    let labels = labels.into_iter().collect::<HashMap<_, _>>();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let old = field.get_value(before).to_string();
+            let new = field.get_value(after).to_string();
+            (old != new).then(|| FieldChange {
+                label: labels
+                    .get(&field)
+                    .cloned()
+                    .unwrap_or_else(|| field.get_name().to_owned()),
+                field,
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+fn collect_labels<T: CrudDataTrait>(elements: &[Elem<T>], labels: &mut Vec<(T::Field, String)>) {
+    for elem in elements {
+        match elem {
+            Elem::Enclosing(Enclosing::None(group)) | Elem::Enclosing(Enclosing::Card(group)) => {
+                collect_labels(&group.children, labels)
+            }
+            Elem::Enclosing(Enclosing::Tabs(tabs)) => {
+                for tab in tabs {
+                    collect_labels(&tab.group.children, labels);
+                }
+            }
+            Elem::Field((field, options)) => labels.push((
+                field.clone(),
+                options
+                    .label
+                    .as_ref()
+                    .map_or_else(|| field.get_name().to_owned(), |label| label.name.clone()),
+            )),
+            Elem::Separator => {}
+        }
+    }
+}
+
+pub enum Msg {
+    OnCancel,
+    OnConfirm,
+    GlobalKeyUp(Rc<GlobalKeyUp>),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props<T: CrudDataTrait> {
+    pub changes: Vec<FieldChange<T>>,
+    pub on_revert: Callback<T::Field>,
+    pub on_cancel: Callback<()>,
+    pub on_confirm: Callback<()>,
+}
+
+/// Lists the changes the user made, before they are saved. Single changes can be reverted.
+pub struct CrudReviewChangesModal<T: CrudDataTrait> {
+    _global_key_up_dispatch: Dispatch<GlobalKeyUp>,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T: 'static + CrudDataTrait> Component for CrudReviewChangesModal<T> {
+    type Message = Msg;
+    type Properties = Props<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            _global_key_up_dispatch: Dispatch::subscribe(ctx.link().callback(Msg::GlobalKeyUp)),
+            phantom_data: PhantomData {},
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::OnCancel => {
+                ctx.props().on_cancel.emit(());
+            }
+            Msg::OnConfirm => {
+                ctx.props().on_confirm.emit(());
+            }
+            Msg::GlobalKeyUp(state) => {
+                if let Some(event) = state.latest_event() {
+                    if event.key().as_str() == "Escape" {
+                        ctx.props().on_cancel.emit(());
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class={"crud-modal"}>
+                <div class={"crud-modal-header"}>
+                    <div class={"crud-modal-title"}>
+                        {"Änderungen prüfen"}
+                    </div>
+                </div>
+
+                <div class={"crud-modal-body"}>
+                    if ctx.props().changes.is_empty() {
+                        {"Es gibt keine Änderungen."}
+                    } else {
+                        <table class={"crud-review-changes"}>
+                            <thead>
+                                <tr>
+                                    <th>{"Feld"}</th>
+                                    <th>{"Vorher"}</th>
+                                    <th>{"Nachher"}</th>
+                                    <th></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {
+                                    ctx.props().changes.iter().map(|change| {
+                                        let field = change.field.clone();
+                                        let on_revert = ctx.props().on_revert.clone();
+                                        html! {
+                                            <tr>
+                                                <td>{change.label.clone()}</td>
+                                                <td class={"before"}>{change.before.clone()}</td>
+                                                <td class={"after"}>{change.after.clone()}</td>
+                                                <td>
+                                                    <CrudBtn name={"Zurücksetzen"} variant={Variant::Default} size={crate::crud_btn::Size::Small} icon={Bi::ArrowCounterclockwise} onclick={move |_| on_revert.emit(field.clone())}/>
+                                                </td>
+                                            </tr>
+                                        }
+                                    }).collect::<Html>()
+                                }
+                            </tbody>
+                        </table>
+                    }
+                </div>
+
+                <div class={"crud-modal-footer"}>
+                    <div class={"crud-row"}>
+                    <div class={"crud-col crud-col-flex-end"}>
+                        <CrudBtnWrapper>
+                            <CrudBtn name={"Zurück"} variant={Variant::Default} onclick={&ctx.link().callback(|_| Msg::OnCancel)}/>
+                            <CrudBtn name={"Speichern"} variant={Variant::Primary} onclick={&ctx.link().callback(|_| Msg::OnConfirm)}/>
+                        </CrudBtnWrapper>
+                    </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -5,6 +5,9 @@ use crate::Label;
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub label: Label,
+    /// Shown next to the label, e.g. the number of modified fields within this tab.
+    #[prop_or_default]
+    pub badge: Option<usize>,
     pub children: Children,
 }
 
@@ -1,22 +1,105 @@
 use crudkit_shared::Order;
 use gloo::timers::callback::Interval;
-use std::rc::Rc;
+use std::{fmt::Debug, rc::Rc};
 use yew::{html::ChildrenRenderer, prelude::*};
 use yew_bootstrap_icons::v1_10_3::Bi;
 
-use crate::{crud_instance::Item, types::custom_field::CustomFields};
+use crate::{
+    crud_instance::Item,
+    crud_table_header::{column_style, sticky_offsets},
+    types::{custom_field::CustomFields, permissions::EntityOperation},
+};
 
 use super::prelude::*;
 
 const MILLIS_UNTIL_ERROR_IS_SHOWN: u32 = 1000;
 
+/// Renders the editor of a single cell, given the entity of the row, the field of the column and a callback closing the editor.
+#[derive(Clone)]
+pub struct InlineEditor<T: CrudDataTrait> {
+    pub renderer: Rc<dyn Fn(&T, &T::Field, &HeaderOptions, Callback<()>) -> Html>,
+    /// Whether a specific cell may be edited.
+    pub editable: Rc<dyn Fn(&T, &T::Field) -> bool>,
+}
+
+impl<T: CrudDataTrait> Debug for InlineEditor<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InlineEditor").finish()
+    }
+}
+
+impl<T: CrudDataTrait> PartialEq for InlineEditor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.renderer, &other.renderer) && Rc::ptr_eq(&self.editable, &other.editable)
+    }
+}
+
+/// Decides whether an operation may be performed on the entity of a specific row.
+#[derive(Clone)]
+pub struct RowPermissions<T: CrudDataTrait> {
+    pub allowed: Rc<dyn Fn(&T, EntityOperation) -> bool>,
+}
+
+impl<T: CrudDataTrait> RowPermissions<T> {
+    /// Whether the given operation may be performed on the entity of a row.
+    /// `read_allowed`, `edit_allowed` and `delete_allowed` apply to all rows. Row permissions, if present, restrict them further.
+    pub fn allows(
+        row_permissions: Option<&Self>,
+        (read_allowed, edit_allowed, delete_allowed): (bool, bool, bool),
+        entity: &T,
+        operation: EntityOperation,
+    ) -> bool {
+        let allowed = match operation {
+            EntityOperation::Read => read_allowed,
+            EntityOperation::Edit => edit_allowed,
+            EntityOperation::Delete => delete_allowed,
+        };
+        allowed && row_permissions.map_or(true, |permissions| (permissions.allowed)(entity, operation))
+    }
+}
+
+impl<T: CrudDataTrait> Debug for RowPermissions<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowPermissions").finish()
+    }
+}
+
+impl<T: CrudDataTrait> PartialEq for RowPermissions<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.allowed, &other.allowed)
+    }
+}
+
+/// Renders the details of an expanded row.
+#[derive(Clone)]
+pub struct RowDetails<T: CrudDataTrait> {
+    pub renderer: Rc<dyn Fn(&T) -> Html>,
+}
+
+impl<T: CrudDataTrait> Debug for RowDetails<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowDetails").finish()
+    }
+}
+
+impl<T: CrudDataTrait> PartialEq for RowDetails<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.renderer, &other.renderer)
+    }
+}
+
 pub enum Msg<T: CrudDataTrait> {
     Select(T, bool),
+    ToggleExpanded(T, bool),
     SelectAll(bool),
     OrderBy((T::Field, OrderByUpdateOptions)),
+    ColumnResized((T::Field, u32)),
     Read(T),
     Edit(T),
     Delete(T),
+    StartInlineEdit((T, T::Field)),
+    StopInlineEdit,
+    KeyDown(KeyboardEvent),
     ActionTriggered((Rc<Box<dyn CrudActionTrait>>, T)),
     SetError(NoData),
 }
@@ -30,22 +113,69 @@ where
     pub custom_fields: CustomFields<T>,
     pub api_base_url: String,
     pub data: Option<Rc<Vec<T>>>,
+    /// All entities which get selected when using the "select all" checkbox. Defaults to `data`.
+    /// Must be given if `data` only contains a window of the entities of interest.
+    #[prop_or_default]
+    pub selectable: Option<Rc<Vec<T>>>,
+    /// Height (in pixels) of the empty space rendered before the rows. Stands in for rows outside of the rendered window.
+    #[prop_or_default]
+    pub spacer_before: u32,
+    /// Height (in pixels) of the empty space rendered after the rows. Stands in for rows outside of the rendered window.
+    #[prop_or_default]
+    pub spacer_after: u32,
     pub no_data: Option<(NoData, time::OffsetDateTime)>,
     pub headers: Vec<(T::Field, HeaderOptions, Option<Order>)>,
     pub on_order_by: Callback<(T::Field, OrderByUpdateOptions)>,
+    #[prop_or_default]
+    pub on_column_resized: Callback<(T::Field, u32)>,
+    #[prop_or_default]
+    pub options: TableOptions,
     pub read_allowed: bool,
     pub edit_allowed: bool,
     pub delete_allowed: bool,
+    /// Further restricts `read_allowed`, `edit_allowed` and `delete_allowed` for individual rows.
+    #[prop_or_default]
+    pub row_permissions: Option<RowPermissions<T>>,
+    /// Enables inline editing of cells if present.
+    #[prop_or_default]
+    pub inline_editor: Option<InlineEditor<T>>,
+    /// Allows rows to be expanded, showing their details below them, if present.
+    #[prop_or_default]
+    pub row_details: Option<RowDetails<T>>,
+    #[prop_or_default]
+    pub expanded: Vec<T>,
+    #[prop_or_default]
+    pub on_toggle_expanded: Callback<(T, bool)>,
     pub selected: Vec<T>,
     pub on_selection: Callback<Vec<T>>,
     pub on_read: Callback<T>,
     pub on_edit: Callback<T>,
     pub on_delete: Callback<T>,
+    /// Called when the user presses Page Up.
+    #[prop_or_default]
+    pub on_previous_page: Callback<()>,
+    /// Called when the user presses Page Down.
+    #[prop_or_default]
+    pub on_next_page: Callback<()>,
     pub additional_item_actions: Vec<Rc<Box<dyn CrudActionTrait>>>,
     pub on_additional_item_action: Callback<(Rc<Box<dyn CrudActionTrait>>, T)>,
 }
 
 impl<T: 'static + CrudDataTrait> Props<T> {
+    pub fn selectable(&self) -> Option<&Rc<Vec<T>>> {
+        self.selectable.as_ref().or(self.data.as_ref())
+    }
+
+    /// Whether the given operation may be performed on the entity of a row.
+    pub fn allows(&self, entity: &T, operation: EntityOperation) -> bool {
+        RowPermissions::allows(
+            self.row_permissions.as_ref(),
+            (self.read_allowed, self.edit_allowed, self.delete_allowed),
+            entity,
+            operation,
+        )
+    }
+
     pub fn has_actions(&self) -> bool {
         !self.additional_item_actions.is_empty()
             || self.read_allowed
@@ -58,6 +188,13 @@ pub struct CrudTable<T> {
     error: Option<NoData>,
     clock_handle: Option<Interval>,
     selected: Vec<T>,
+    /// The cell currently being edited inline.
+    editing: Option<(T, T::Field)>,
+    /// The row currently focused through keyboard navigation.
+    focused: Option<T>,
+    /// Set when the focused row changed and must be scrolled into view after the next render.
+    scroll_to_focused: bool,
+    table_ref: NodeRef,
 }
 
 impl<T: 'static + CrudDataTrait> CrudTable<T> {
@@ -90,6 +227,23 @@ where
             error: None,
             clock_handle: None,
             selected: ctx.props().selected.clone(),
+            editing: None,
+            focused: None,
+            scroll_to_focused: false,
+            table_ref: NodeRef::default(),
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if self.scroll_to_focused {
+            self.scroll_to_focused = false;
+            if let Some(row) = self
+                .table_ref
+                .cast::<web_sys::Element>()
+                .and_then(|table| table.query_selector("tr.focused").ok().flatten())
+            {
+                row.scroll_into_view_with_bool(false);
+            }
         }
     }
 
@@ -97,6 +251,12 @@ where
     /// And data is present: Creates a new clock, which waits `MILLIS_UNTIL_ERROR_IS_SHOWN` milliseconds and displays the error.
     /// And data is not present: Removes the error and any leftover clock.
     fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.data != ctx.props().data {
+            self.editing = None;
+        }
+        if old_props.selected != ctx.props().selected {
+            self.selected = ctx.props().selected.clone();
+        }
         if old_props.no_data != ctx.props().no_data {
             match &ctx.props().no_data {
                 Some((no_data, _since)) => {
@@ -120,6 +280,69 @@ where
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
+            Msg::KeyDown(event) => {
+                // Keys pressed inside of the table (for example in an inline editor) are none of our business.
+                if event.target() != event.current_target() || self.editing.is_some() {
+                    return false;
+                }
+                let data = match &ctx.props().data {
+                    Some(data) if !data.is_empty() => data.clone(),
+                    _ => return false,
+                };
+                let position = self
+                    .focused
+                    .as_ref()
+                    .and_then(|focused| data.iter().position(|it| it == focused));
+                let focus = |this: &mut Self, index: usize| {
+                    this.focused = data.get(index).cloned();
+                    this.scroll_to_focused = true;
+                    true
+                };
+                let focused = self.focused.clone();
+                let rerender = match (event.key().as_str(), &focused) {
+                    ("ArrowDown", _) => {
+                        focus(self, position.map_or(0, |pos| usize::min(pos + 1, data.len() - 1)))
+                    }
+                    ("ArrowUp", _) => focus(self, position.map_or(0, |pos| pos.saturating_sub(1))),
+                    ("Home", _) => focus(self, 0),
+                    ("End", _) => focus(self, data.len() - 1),
+                    (" ", Some(focused)) => {
+                        let selected = self.selected.contains(focused);
+                        ctx.link()
+                            .send_message(Msg::Select(focused.clone(), !selected));
+                        false
+                    }
+                    ("Enter", Some(focused)) => {
+                        if ctx.props().allows(focused, EntityOperation::Edit) {
+                            ctx.props().on_edit.emit(focused.clone());
+                        } else if ctx.props().allows(focused, EntityOperation::Read) {
+                            ctx.props().on_read.emit(focused.clone());
+                        }
+                        false
+                    }
+                    ("Delete", Some(focused)) => {
+                        if ctx.props().allows(focused, EntityOperation::Delete) {
+                            ctx.props().on_delete.emit(focused.clone());
+                        }
+                        false
+                    }
+                    ("PageUp", _) => {
+                        ctx.props().on_previous_page.emit(());
+                        false
+                    }
+                    ("PageDown", _) => {
+                        ctx.props().on_next_page.emit(());
+                        false
+                    }
+                    _ => return false,
+                };
+                event.prevent_default();
+                rerender
+            }
+            Msg::ToggleExpanded(entity, expanded) => {
+                ctx.props().on_toggle_expanded.emit((entity, expanded));
+                false
+            }
             Msg::Select(entity, state) => {
                 let pos = self.selected.iter().position(|it| it == &entity);
                 match (pos, state) {
@@ -138,7 +361,7 @@ where
                 match state {
                     true => {
                         self.selected.clear();
-                        if let Some(data) = &ctx.props().data {
+                        if let Some(data) = ctx.props().selectable() {
                             for entity in data.as_ref() {
                                 self.selected.push(entity.clone());
                             }
@@ -165,10 +388,22 @@ where
                 ctx.props().on_delete.emit(entity);
                 false
             }
+            Msg::StartInlineEdit(cell) => {
+                self.editing = Some(cell);
+                true
+            }
+            Msg::StopInlineEdit => {
+                self.editing = None;
+                true
+            }
             Msg::OrderBy(field) => {
                 ctx.props().on_order_by.emit(field);
                 false
             }
+            Msg::ColumnResized(resized) => {
+                ctx.props().on_column_resized.emit(resized);
+                false
+            }
             Msg::SetError(no_data) => {
                 self.error = Some(no_data);
                 self.clock_handle = None;
@@ -180,18 +415,33 @@ where
     fn view(&self, ctx: &Context<Self>) -> Html {
         let link = ctx.link();
         let has_actions = ctx.props().has_actions();
+        let sticky_offsets = sticky_offsets(&ctx.props().headers, true);
+        let first_column_sticky = sticky_offsets.first().map_or(false, Option::is_some);
+        let selectable_count = ctx.props().selectable().map_or(0, |it| it.len());
         html! {
             <div class={"crud-table-wrapper"}>
-                <table class={"crud-table crud-table-bordered crud-table-hoverable"}>
+                <table
+                    class={"crud-table crud-table-bordered crud-table-hoverable"}
+                    role={"grid"}
+                    aria-multiselectable={"true"}
+                    tabindex={"0"}
+                    ref={self.table_ref.clone()}
+                    onkeydown={ctx.link().callback(Msg::KeyDown)}
+                >
                     <CrudTableHeader<T>
                         headers={ctx.props().headers.clone()}
                         on_order_by={ctx.link().callback(Msg::OrderBy)}
+                        on_column_resized={ctx.link().callback(Msg::ColumnResized)}
+                        options={ctx.props().options.clone()}
                         with_actions={has_actions}
                         with_select_column={ctx.props().data.is_some() && ctx.props().data.as_ref().unwrap().len() > 0}
-                        all_selected={ctx.props().data.is_some() && self.selected.len() == ctx.props().data.as_ref().unwrap().len() && ctx.props().data.as_ref().unwrap().len() > 0}
+                        all_selected={selectable_count > 0 && self.selected.len() == selectable_count}
                         on_select_all={ctx.link().callback(Msg::SelectAll)}
                     />
                     <tbody>
+                        if ctx.props().data.is_some() && ctx.props().spacer_before > 0 {
+                            <tr class={"crud-table-spacer"} style={format!("height: {}px;", ctx.props().spacer_before)}></tr>
+                        }
                         {
                             if let Some(data) = &ctx.props().data {
                                 match data.len() {
@@ -205,38 +455,84 @@ where
                                     _ => data.iter().map(|entity| {
                                         let cloned_entity = entity.clone();
                                         let cloned_entity_2 = entity.clone();
+                                        let cloned_entity_3 = entity.clone();
+                                        let expanded = ctx.props().row_details.is_some() && ctx.props().expanded.contains(entity);
+                                        let selected = self.selected.contains(entity);
+                                        let focused = self.focused.as_ref() == Some(entity);
+                                        // A double-click also produces two clicks. They must not open the edit view when editing inline.
+                                        let read_allowed = ctx.props().allows(entity, EntityOperation::Read);
+                                        let edit_allowed = ctx.props().allows(entity, EntityOperation::Edit);
+                                        let delete_allowed = ctx.props().allows(entity, EntityOperation::Delete);
+                                        let onclick = match (&ctx.props().inline_editor, edit_allowed, read_allowed) {
+                                            (Some(_), _, _) => None,
+                                            (None, true, _) => Some(link.callback(move |_| Msg::Edit(cloned_entity.clone()))),
+                                            (None, false, true) => Some(link.callback(move |_| Msg::Read(cloned_entity.clone()))),
+                                            (None, false, false) => None,
+                                        };
                                         html! {
-                                            <tr class={"interactable"}
-                                                onclick={link.callback(move |_| Msg::Edit(cloned_entity.clone()))}
+                                            <>
+                                            <tr
+                                                class={classes!("interactable", expanded.then(|| "expanded"), focused.then(|| "focused"))}
+                                                role={"row"}
+                                                aria-selected={selected.to_string()}
+                                                aria-expanded={ctx.props().row_details.as_ref().map(|_| expanded.to_string())}
+                                                {onclick}
                                             >
-                                                <td class={"select"} onclick={|it: MouseEvent| { it.stop_propagation() }}>
+                                                <td class={classes!("select", first_column_sticky.then(|| "sticky"))} role={"gridcell"} onclick={|it: MouseEvent| { it.stop_propagation() }}>
                                                     <CrudCheckbox
-                                                        state={self.selected.iter().find(|it| it == &entity).is_some()}
+                                                        state={selected}
                                                         on_toggle={ctx.link().callback(move |state| Msg::Select(cloned_entity_2.clone(), state))}/>
+                                                    if ctx.props().row_details.is_some() {
+                                                        <div
+                                                            class={"crud-row-expand-toggle"}
+                                                            title={match expanded { true => "Details ausblenden", false => "Details anzeigen" }}
+                                                            onclick={ctx.link().callback(move |_| Msg::ToggleExpanded(cloned_entity_3.clone(), !expanded))}
+                                                        >
+                                                            <CrudIcon variant={match expanded { true => Bi::ChevronDown, false => Bi::ChevronRight }}/>
+                                                        </div>
+                                                    }
                                                 </td>
                                                 {
-                                                    ctx.props().headers.iter().map(|(field, options, _order)| {
+                                                    ctx.props().headers.iter().zip(sticky_offsets.iter()).map(|((field, options, _order), sticky_offset)| {
+                                                        let editing = self.editing.as_ref().map_or(false, |(e, f)| e == entity && f == field);
+                                                        let ondblclick = ctx.props().inline_editor.as_ref().filter(|it| (it.editable)(entity, field)).map(|_| {
+                                                            let cell = (entity.clone(), field.clone());
+                                                            link.callback(move |_: MouseEvent| Msg::StartInlineEdit(cell.clone()))
+                                                        });
                                                         html! {
-                                                            <td>
+                                                            <td
+                                                                class={classes!(sticky_offset.is_some().then(|| "sticky"), editing.then(|| "inline-editing"))}
+                                                                role={"gridcell"}
+                                                                style={column_style(options, *sticky_offset)}
+                                                                {ondblclick}
+                                                            >
+                                                            if let (true, Some(inline_editor)) = (editing, &ctx.props().inline_editor) {
+                                                                { (inline_editor.renderer)(entity, field, options, link.callback(|_| Msg::StopInlineEdit)) }
+                                                            } else {
                                                                 <CrudField<T>
                                                                     children={ctx.props().children.clone()}
                                                                     custom_fields={ctx.props().custom_fields.clone()}
                                                                     api_base_url={ctx.props().api_base_url.clone()}
                                                                     current_view={CrudSimpleView::List}
                                                                     field_type={field.clone()}
-                                                                    field_options={FieldOptions { disabled: false, label: None, date_time_display: options.date_time_display }}
+                                                                    field_options={FieldOptions { disabled: false, label: None, date_time_display: options.date_time_display, formatter: options.formatter.clone(), visible_if: None, enabled_if: None }}
                                                                     entity={entity.clone()}
                                                                     field_mode={FieldMode::Display}
                                                                     value_changed={|_| {}}
                                                                 />
+                                                            }
                                                             </td>
                                                         }
                                                     }).collect::<Html>()
                                                 }
                                                 if has_actions {
-                                                    <td onclick={|it: MouseEvent| { it.stop_propagation() }}>
+                                                    <td
+                                                        class={classes!(ctx.props().options.sticky_actions.then(|| "sticky-right"))}
+                                                        role={"gridcell"}
+                                                        onclick={|it: MouseEvent| { it.stop_propagation() }}
+                                                    >
                                                         <div class={"action-icons"}>
-                                                            if ctx.props().read_allowed {
+                                                            if read_allowed {
                                                                 <div
                                                                     class={"action-icon"}
                                                                     onclick={link.callback(CrudTable::<T>::create_read_callback(entity.clone()))}
@@ -244,7 +540,7 @@ where
                                                                     <CrudIcon variant={Bi::Eye}/>
                                                                 </div>
                                                             }
-                                                            if ctx.props().edit_allowed {
+                                                            if edit_allowed {
                                                                 <div
                                                                     class={"action-icon"}
                                                                     onclick={link.callback(CrudTable::<T>::create_edit_callback(entity.clone()))}
@@ -252,7 +548,7 @@ where
                                                                     <CrudIcon variant={Bi::Pencil}/>
                                                                 </div>
                                                             }
-                                                            if ctx.props().delete_allowed {
+                                                            if delete_allowed {
                                                                 <div
                                                                     class={"action-icon"}
                                                                     onclick={link.callback(CrudTable::<T>::create_delete_callback(entity.clone()))}
@@ -278,6 +574,14 @@ where
                                                     </td>
                                                 }
                                             </tr>
+                                            if let (true, Some(row_details)) = (expanded, &ctx.props().row_details) {
+                                                <tr class={"crud-table-row-details"}>
+                                                    <td colspan={"100%"}>
+                                                        { (row_details.renderer)(entity) }
+                                                    </td>
+                                                </tr>
+                                            }
+                                            </>
                                         }
                                     }).collect::<Html>()
                                 }
@@ -301,6 +605,9 @@ where
                                 html! { "Component misconfigured: Either pass some data or an error, not both." }
                             }
                         }
+                        if ctx.props().data.is_some() && ctx.props().spacer_after > 0 {
+                            <tr class={"crud-table-spacer"} style={format!("height: {}px;", ctx.props().spacer_after)}></tr>
+                        }
                         {
                             if let Some(reason) = &self.error {
                                 html! {
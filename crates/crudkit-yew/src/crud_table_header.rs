@@ -1,11 +1,75 @@
 use super::prelude::*;
+use crate::stores::{
+    global_mouse_move::{GlobalMouseMove, GlobalMouseMoveRequired},
+    global_mouse_up::GlobalMouseUp,
+};
 use crudkit_shared::Order;
-use std::marker::PhantomData;
+use std::rc::Rc;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
+use yewdux::prelude::*;
+
+/// Width of the selection column. Required to compute the offsets of sticky columns following it.
+pub(crate) const SELECT_COLUMN_WIDTH: u32 = 40;
+
+/// Width used for sticky columns which were not given an explicit width.
+pub(crate) const DEFAULT_STICKY_COLUMN_WIDTH: u32 = 150;
+
+/// Columns can not be resized to be smaller than this.
+const MIN_COLUMN_WIDTH: f64 = 40.0;
+
+/// Computes the `left` offset (in pixels) of every sticky column. Non-sticky columns get `None`.
+/// A column is only sticky if all columns in front of it are sticky as well.
+/// The selection column is sticky if the first column is sticky.
+pub(crate) fn sticky_offsets<F>(
+    headers: &[(F, HeaderOptions, Option<Order>)],
+    with_select_column: bool,
+) -> Vec<Option<u32>> {
+    let mut left = match with_select_column {
+        true => SELECT_COLUMN_WIDTH,
+        false => 0,
+    };
+    let mut sticky = true;
+    headers
+        .iter()
+        .map(|(_field, options, _order)| {
+            sticky = sticky && options.sticky;
+            match sticky {
+                true => {
+                    let offset = left;
+                    left += options.width.unwrap_or(DEFAULT_STICKY_COLUMN_WIDTH);
+                    Some(offset)
+                }
+                false => None,
+            }
+        })
+        .collect()
+}
+
+/// Inline style of a column, fixing its width and, if sticky, its horizontal position.
+pub(crate) fn column_style(options: &HeaderOptions, sticky_offset: Option<u32>) -> Option<String> {
+    let width = match sticky_offset {
+        Some(_) => Some(options.width.unwrap_or(DEFAULT_STICKY_COLUMN_WIDTH)),
+        None => options.width,
+    };
+    match (width, sticky_offset) {
+        (None, _) => None,
+        (Some(width), None) => Some(format!(
+            "width: {width}px; min-width: {width}px; max-width: {width}px;"
+        )),
+        (Some(width), Some(left)) => Some(format!(
+            "left: {left}px; width: {width}px; min-width: {width}px; max-width: {width}px;"
+        )),
+    }
+}
 
 pub enum Msg<T: CrudDataTrait> {
     OrderBy((T::Field, HeaderOptions)),
     SelectAll(bool),
+    ResizeStart((T::Field, MouseEvent)),
+    GlobalMouseMove(Rc<GlobalMouseMove>),
+    GlobalMouseUp(Rc<GlobalMouseUp>),
 }
 
 #[derive(Properties, PartialEq)]
@@ -15,6 +79,9 @@ where
 {
     pub headers: Vec<(T::Field, HeaderOptions, Option<Order>)>,
     pub on_order_by: Callback<(T::Field, OrderByUpdateOptions)>,
+    /// Called with the new width (in pixels) of a column after the user resized it.
+    pub on_column_resized: Callback<(T::Field, u32)>,
+    pub options: TableOptions,
     pub with_actions: bool,
     /// Should be true if all entities are selected.
     pub with_select_column: bool,
@@ -22,17 +89,42 @@ where
     pub on_select_all: Callback<bool>,
 }
 
-pub struct CrudTableHeader<T> {
-    phantom: PhantomData<T>,
+struct Resizing<T: CrudDataTrait> {
+    field: T::Field,
+    start_x: f64,
+    start_width: f64,
+    width: f64,
+}
+
+pub struct CrudTableHeader<T: CrudDataTrait> {
+    _global_mouse_up_dispatch: Dispatch<GlobalMouseUp>,
+    _global_mouse_move_dispatch: Dispatch<GlobalMouseMove>,
+    global_mouse_move_required_dispatch: Dispatch<GlobalMouseMoveRequired>,
+    uuid: Uuid,
+    resizing: Option<Resizing<T>>,
 }
 
 impl<T: 'static + CrudDataTrait> Component for CrudTableHeader<T> {
     type Message = Msg<T>;
     type Properties = Props<T>;
 
-    fn create(_ctx: &Context<Self>) -> Self {
+    fn create(ctx: &Context<Self>) -> Self {
         Self {
-            phantom: PhantomData {},
+            _global_mouse_up_dispatch: Dispatch::subscribe(ctx.link().callback(Msg::GlobalMouseUp)),
+            _global_mouse_move_dispatch: Dispatch::subscribe(
+                ctx.link().callback(Msg::GlobalMouseMove),
+            ),
+            global_mouse_move_required_dispatch: Dispatch::new(),
+            uuid: Uuid::new_v4(),
+            resizing: None,
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if self.resizing.is_some() {
+            let uuid = self.uuid;
+            self.global_mouse_move_required_dispatch
+                .reduce_mut(move |state| state.not_require_by(uuid));
         }
     }
 
@@ -50,21 +142,83 @@ impl<T: 'static + CrudDataTrait> Component for CrudTableHeader<T> {
                 ctx.props().on_select_all.emit(state);
                 false
             }
+            Msg::ResizeStart((field, event)) => {
+                event.prevent_default();
+                event.stop_propagation();
+                // The resize handle is placed directly inside the header cell whose width we are interested in.
+                let start_width = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    .and_then(|handle| handle.parent_element())
+                    .map(|th| th.get_bounding_client_rect().width());
+                if let Some(start_width) = start_width {
+                    let uuid = self.uuid;
+                    self.global_mouse_move_required_dispatch
+                        .reduce_mut(move |state| state.require_by(uuid));
+                    self.resizing = Some(Resizing {
+                        field,
+                        start_x: event.client_x() as f64,
+                        start_width,
+                        width: start_width,
+                    });
+                }
+                false
+            }
+            Msg::GlobalMouseMove(gmm) => match (&mut self.resizing, gmm.latest_event()) {
+                (Some(resizing), Some(event)) => {
+                    resizing.width = f64::max(
+                        MIN_COLUMN_WIDTH,
+                        resizing.start_width + event.client_x() as f64 - resizing.start_x,
+                    );
+                    true
+                }
+                _ => false,
+            },
+            Msg::GlobalMouseUp(_state) => match self.resizing.take() {
+                Some(resizing) => {
+                    let uuid = self.uuid;
+                    self.global_mouse_move_required_dispatch
+                        .reduce_mut(move |state| state.not_require_by(uuid));
+                    ctx.props()
+                        .on_column_resized
+                        .emit((resizing.field, resizing.width.round() as u32));
+                    true
+                }
+                None => false,
+            },
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        // While resizing, the new width is only known to this component. It is shown immediately.
+        let headers = ctx
+            .props()
+            .headers
+            .iter()
+            .map(|(field, options, order)| {
+                let mut options = options.clone();
+                if let Some(resizing) = &self.resizing {
+                    if &resizing.field == field {
+                        options.width = Some(resizing.width.round() as u32);
+                    }
+                }
+                (field.clone(), options, order.clone())
+            })
+            .collect::<Vec<_>>();
+        let sticky_offsets = sticky_offsets(&headers, ctx.props().with_select_column);
+        let first_column_sticky = sticky_offsets.first().map_or(false, Option::is_some);
+
         html! {
-            <thead class={"crud-table-header"}>
-                <tr>
+            <thead class={classes!("crud-table-header", ctx.props().options.sticky_header.then(|| "sticky"))}>
+                <tr role={"row"}>
                     if ctx.props().with_select_column {
-                        <th class={"select min-width"}>
+                        <th class={classes!("select", "min-width", first_column_sticky.then(|| "sticky"))} role={"columnheader"}>
                             <CrudCheckbox state={ctx.props().all_selected} on_toggle={ctx.link().callback(Msg::SelectAll)}/>
                         </th>
                     }
 
                     {
-                        ctx.props().headers.iter().map(|(field, options, order)| {
+                        headers.iter().zip(sticky_offsets.iter()).map(|((field, options, order), sticky_offset)| {
                             let mut classes = classes!("crud-column-header");
                             if order.is_some() {
                                 classes.push("crud-column-ordered");
@@ -75,12 +229,23 @@ impl<T: 'static + CrudDataTrait> Component for CrudTableHeader<T> {
                             if options.min_width {
                                 classes.push("min-width");
                             }
+                            if sticky_offset.is_some() {
+                                classes.push("sticky");
+                            }
 
                             let field_clone = field.clone();
                             let options_clone = options.clone();
+                            let resized_field = field.clone();
                             html! {
                                 <th
                                     class={classes}
+                                    role={"columnheader"}
+                                    aria-sort={match order {
+                                        Some(Order::Asc) => "ascending",
+                                        Some(Order::Desc) => "descending",
+                                        None => "none",
+                                    }}
+                                    style={column_style(options, *sticky_offset)}
                                     onclick={ctx.link().callback(move |_| Msg::OrderBy((field_clone.clone(), options_clone.clone())))}
                                 >
                                     <div class={"crud-row"}>
@@ -101,12 +266,17 @@ impl<T: 'static + CrudDataTrait> Component for CrudTableHeader<T> {
 
                                         </div>
                                     </div>
+                                    <div
+                                        class={classes!("crud-column-resize-handle", self.resizing.as_ref().map_or(false, |it| &it.field == field).then(|| "active"))}
+                                        onmousedown={ctx.link().callback(move |event| Msg::ResizeStart((resized_field.clone(), event)))}
+                                        onclick={|event: MouseEvent| event.stop_propagation()}
+                                    />
                                 </th>
                             }
                         }).collect::<Html>()
                     }
                     if ctx.props().with_actions {
-                        <th class={"actions min-width"}>
+                        <th class={classes!("actions", "min-width", ctx.props().options.sticky_actions.then(|| "sticky-right"))} role={"columnheader"}>
                             {"Aktionen"}
                         </th>
                     }
@@ -76,7 +76,7 @@ impl Component for CrudTabs {
             <div class={"crud-tabs"}>
                 <div class={"crud-tab-selectors"}>
                     {
-                        for ctx.props().children.iter().map(|tab| tab.props.label.clone()).map(|tab_label| {
+                        for ctx.props().children.iter().map(|tab| (tab.props.label.clone(), tab.props.badge)).map(|(tab_label, badge)| {
                             let tab_clone = tab_label.clone();
                             let is_active = match &self.active_tab {
                                 Some(active_tab_label) => active_tab_label == &tab_label,
@@ -86,6 +86,9 @@ impl Component for CrudTabs {
                                 <div class={classes!("crud-tab-selector", is_active.then(|| "active"))}
                                      onclick={ctx.link().callback(move |_| Msg::TabSelected(tab_clone.clone()))}>
                                     {tab_label.name.clone()}
+                                    if let Some(badge) = badge {
+                                        <span class={"crud-tab-badge"}>{badge}</span>
+                                    }
                                 </div>
                             }
                         })
@@ -0,0 +1,196 @@
+use tracing::error;
+use yew::prelude::*;
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use crate::{event_target_as, keyboard_event_target_as};
+
+use super::prelude::*;
+
+pub enum Msg {
+    KeyDown(KeyboardEvent),
+    Commit(Event),
+    Remove(usize),
+    DragStart(usize),
+    DragEnd,
+    Drop(usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub id: String,
+    pub tags: Vec<String>,
+    /// Type of the input in which new tags are entered, e.g. "number".
+    #[prop_or("text")]
+    pub input_type: &'static str,
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub onchange: Option<Callback<Vec<String>>>,
+}
+
+/// Edits a list of values, displayed as tags.
+/// A tag is added by pressing Enter or "," or by leaving the input. Backspace in the empty input removes the last tag.
+/// Tags can be reordered using drag and drop.
+/// The tags are kept in the component, so that changes are shown even if the parent does not render again.
+pub struct CrudTagInput {
+    /// The tags currently shown. Replaced whenever the `tags` prop changes.
+    tags: Vec<String>,
+    /// Index of the tag currently being dragged.
+    dragged: Option<usize>,
+}
+
+impl CrudTagInput {
+    fn emit(&mut self, ctx: &Context<Self>, tags: Vec<String>) {
+        self.tags = tags.clone();
+        if let Some(onchange) = &ctx.props().onchange {
+            onchange.emit(tags);
+        }
+    }
+
+    /// Adds the content of the input as a new tag and empties the input.
+    /// Returns whether a tag was added.
+    fn add(&mut self, ctx: &Context<Self>, input: &web_sys::HtmlInputElement) -> bool {
+        let tag = input.value().trim().to_owned();
+        input.set_value("");
+        if tag.is_empty() {
+            return false;
+        }
+        let mut tags = self.tags.clone();
+        tags.push(tag);
+        self.emit(ctx, tags);
+        true
+    }
+}
+
+impl Component for CrudTagInput {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            tags: ctx.props().tags.clone(),
+            dragged: None,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.tags != ctx.props().tags {
+            self.tags = ctx.props().tags.clone();
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::KeyDown(event) => {
+                let key = event.key();
+                match keyboard_event_target_as::<web_sys::HtmlInputElement>(event.clone()) {
+                    Ok(input) => match key.as_str() {
+                        "Enter" | "," => {
+                            event.prevent_default();
+                            self.add(ctx, &input)
+                        }
+                        "Backspace" if input.value().is_empty() => {
+                            let mut tags = self.tags.clone();
+                            let removed = tags.pop().is_some();
+                            if removed {
+                                self.emit(ctx, tags);
+                            }
+                            removed
+                        }
+                        _ => false,
+                    },
+                    Err(err) => {
+                        error!("Could not get input: {err}");
+                        false
+                    }
+                }
+            }
+            Msg::Commit(event) => match event_target_as::<web_sys::HtmlInputElement>(event) {
+                Ok(input) => self.add(ctx, &input),
+                Err(err) => {
+                    error!("Could not get input: {err}");
+                    false
+                }
+            },
+            Msg::Remove(index) => {
+                let mut tags = self.tags.clone();
+                if index >= tags.len() {
+                    return false;
+                }
+                tags.remove(index);
+                self.emit(ctx, tags);
+                true
+            }
+            Msg::DragStart(index) => {
+                self.dragged = Some(index);
+                true
+            }
+            Msg::DragEnd => {
+                self.dragged = None;
+                true
+            }
+            Msg::Drop(target) => {
+                if let Some(source) = self.dragged.take() {
+                    let mut tags = self.tags.clone();
+                    if source != target && source < tags.len() && target < tags.len() {
+                        let tag = tags.remove(source);
+                        tags.insert(target, tag);
+                        self.emit(ctx, tags);
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let disabled = ctx.props().disabled;
+        html! {
+            <div class={classes!("crud-tag-input", "crud-input-field", disabled.then(|| "disabled"))}>
+                {
+                    self.tags.iter().enumerate().map(|(index, tag)| html! {
+                        <span
+                            class={classes!("crud-tag", (self.dragged == Some(index)).then_some("dragged"))}
+                            draggable={(!disabled).to_string()}
+                            ondragstart={ctx.link().callback(move |_: DragEvent| Msg::DragStart(index))}
+                            ondragend={ctx.link().callback(|_: DragEvent| Msg::DragEnd)}
+                            ondragover={|event: DragEvent| event.prevent_default()}
+                            ondrop={ctx.link().callback(move |event: DragEvent| {
+                                event.prevent_default();
+                                Msg::Drop(index)
+                            })}
+                        >
+                            {tag.clone()}
+                            if !disabled {
+                                <span class={"crud-tag-remove"} onclick={ctx.link().callback(move |_| Msg::Remove(index))}>
+                                    <CrudIcon variant={Bi::X}/>
+                                </span>
+                            }
+                        </span>
+                    }).collect::<Html>()
+                }
+                if !disabled {
+                    <input
+                        id={ctx.props().id.clone()}
+                        type={ctx.props().input_type}
+                        placeholder={ctx.props().placeholder.clone()}
+                        onkeydown={ctx.link().callback(Msg::KeyDown)}
+                        onchange={ctx.link().callback(Msg::Commit)}
+                    />
+                }
+            </div>
+        }
+    }
+}
+
+/// Displays the given values as tags, e.g. in a table cell.
+pub fn render_tags(tags: impl Iterator<Item = String>) -> Html {
+    html! {
+        <div class={"crud-tags"}>
+            { tags.map(|tag| html! { <span class={"crud-tag"}>{tag}</span> }).collect::<Html>() }
+        </div>
+    }
+}
@@ -1,27 +1,598 @@
-use yew::prelude::*;
+use std::{collections::HashMap, rc::Rc};
 
-pub struct CrudTree {}
+use crudkit_condition::{Condition, ConditionClause, ConditionElement, IntoAllEqualCondition};
+use crudkit_id::{Id, IdField};
+use crudkit_shared::{Order, SaveResult};
+use indexmap::IndexMap;
+use tracing::warn;
+use yew::{html::ChildrenRenderer, prelude::*};
+use yew_bootstrap_icons::v1_10_3::Bi;
+
+use crate::{
+    crud_instance::Item,
+    crud_table::RowPermissions,
+    null_condition_value,
+    services::crud_rest_data_provider::{CrudRestDataProvider, ReadMany, UpdateOne},
+    types::{custom_field::CustomReadFields, permissions::EntityOperation},
+};
+
+use super::{prelude::*, types::RequestError};
+
+pub enum Msg<T: CrudMainTrait> {
+    RootsLoaded(Result<Vec<T::ReadModel>, RequestError>),
+    ChildrenLoaded((String, Result<Vec<T::ReadModel>, RequestError>)),
+    Toggle(T::ReadModel),
+    Read(T::ReadModel),
+    Edit(T::ReadModel),
+    Delete(T::ReadModel),
+    ActionTriggered((Rc<Box<dyn CrudActionTrait>>, T::ReadModel)),
+    DragStart(T::ReadModel),
+    DragEnd,
+    /// Drop the dragged node onto the given node, or onto the root level if `None`.
+    Drop(Option<T::ReadModel>),
+    Moved(Result<SaveResult<T::UpdateModel>, RequestError>),
+    Reload,
+}
 
 #[derive(Properties, PartialEq)]
-pub struct Props {
-    children: Children,
+pub struct Props<T: 'static + CrudMainTrait> {
+    #[prop_or_default]
+    pub children: ChildrenRenderer<Item>,
+    #[prop_or_default]
+    pub custom_fields: CustomReadFields<T>,
+    pub api_base_url: String,
+    pub data_provider: CrudRestDataProvider<T>,
+    pub headers: Vec<(<T::ReadModel as CrudDataTrait>::Field, HeaderOptions)>,
+    /// The field uniquely identifying a node. Its value is referenced by the `parent_field` of the nodes children.
+    pub id_field: <T::ReadModel as CrudDataTrait>::Field,
+    /// The field referencing the parent node. Nodes without a value in this field are root nodes.
+    pub parent_field: <T::ReadModel as CrudDataTrait>::Field,
+    /// Condition selecting the root nodes.
+    /// If not set, the nodes without a value in the `parent_field` are loaded as roots.
+    #[prop_or_default]
+    pub root_condition: Option<Condition>,
+    #[prop_or_default]
+    pub order_by: IndexMap<<T::ReadModel as CrudDataTrait>::Field, Order>,
+    #[prop_or(true)]
+    pub read_allowed: bool,
+    #[prop_or(true)]
+    pub edit_allowed: bool,
+    #[prop_or(true)]
+    pub delete_allowed: bool,
+    /// Further restricts `read_allowed`, `edit_allowed`, `delete_allowed` and `move_allowed` for individual nodes.
+    #[prop_or_default]
+    pub row_permissions: Option<RowPermissions<T::ReadModel>>,
+    /// Nodes can only be moved if this is true.
+    #[prop_or(true)]
+    pub move_allowed: bool,
+    #[prop_or_default]
+    pub on_read: Callback<T::ReadModel>,
+    #[prop_or_default]
+    pub on_edit: Callback<T::ReadModel>,
+    #[prop_or_default]
+    pub on_delete: Callback<T::ReadModel>,
+    /// Called after a node was successfully moved under a new parent.
+    #[prop_or_default]
+    pub on_moved: Callback<T::UpdateModel>,
+    #[prop_or_default]
+    pub additional_item_actions: Vec<Rc<Box<dyn CrudActionTrait>>>,
+    #[prop_or_default]
+    pub on_additional_item_action: Callback<(Rc<Box<dyn CrudActionTrait>>, T::ReadModel)>,
+}
+
+impl<T: 'static + CrudMainTrait> Props<T> {
+    /// Whether the given operation may be performed on the entity of a node.
+    pub fn allows(&self, entity: &T::ReadModel, operation: EntityOperation) -> bool {
+        RowPermissions::allows(
+            self.row_permissions.as_ref(),
+            (self.read_allowed, self.edit_allowed, self.delete_allowed),
+            entity,
+            operation,
+        )
+    }
+
+    fn movable(&self, entity: &T::ReadModel) -> bool {
+        self.move_allowed && self.allows(entity, EntityOperation::Edit)
+    }
+
+    pub fn has_actions(&self) -> bool {
+        !self.additional_item_actions.is_empty()
+            || self.read_allowed
+            || self.edit_allowed
+            || self.delete_allowed
+    }
 }
 
-impl Component for CrudTree {
-    type Message = ();
-    type Properties = Props;
+/// Displays self-referencing entities as a tree-table.
+/// Only the root nodes are loaded initially. The children of a node are loaded when it gets expanded.
+pub struct CrudTree<T: CrudMainTrait> {
+    roots: Result<Vec<T::ReadModel>, NoData>,
+    /// Loaded children, by the id of their parent. A node whose children are still loading maps to `Err(NoData::NotYetLoaded)`.
+    nodes: HashMap<String, Result<Vec<T::ReadModel>, NoData>>,
+    /// Expanded nodes, by their id.
+    expanded: HashMap<String, T::ReadModel>,
+    dragged: Option<T::ReadModel>,
+    ongoing_move: bool,
+}
+
+impl<T: 'static + CrudMainTrait> CrudTree<T> {
+    fn key(ctx: &Context<Self>, entity: &T::ReadModel) -> String {
+        ctx.props().id_field.get_value(entity).to_string()
+    }
+
+    fn load_roots(&self, ctx: &Context<Self>) {
+        let data_provider = ctx.props().data_provider.clone();
+        let order_by = ctx.props().order_by.clone();
+        let condition = ctx.props().root_condition.clone().unwrap_or_else(|| {
+            Condition::All(vec![ConditionElement::Clause(ConditionClause {
+                column_name: ctx.props().parent_field.get_name().to_owned(),
+                operator: crudkit_condition::Operator::Equal,
+                value: null_condition_value(),
+            })])
+        });
+        ctx.link().send_future(async move {
+            Msg::RootsLoaded(
+                data_provider
+                    .read_many(ReadMany {
+                        limit: None,
+                        skip: None,
+                        order_by: Some(order_by),
+                        condition: Some(condition),
+                    })
+                    .await,
+            )
+        });
+    }
+
+    fn load_children(&mut self, ctx: &Context<Self>, parent: &T::ReadModel) {
+        let key = Self::key(ctx, parent);
+        let condition = Condition::All(vec![ConditionElement::Clause(ConditionClause {
+            column_name: ctx.props().parent_field.get_name().to_owned(),
+            operator: crudkit_condition::Operator::Equal,
+            value: ctx.props().id_field.get_value(parent).into(),
+        })]);
+        let data_provider = ctx.props().data_provider.clone();
+        let order_by = ctx.props().order_by.clone();
+        self.nodes.insert(key.clone(), Err(NoData::NotYetLoaded));
+        ctx.link().send_future(async move {
+            Msg::ChildrenLoaded((
+                key,
+                data_provider
+                    .read_many(ReadMany {
+                        limit: None,
+                        skip: None,
+                        order_by: Some(order_by),
+                        condition: Some(condition),
+                    })
+                    .await,
+            ))
+        });
+    }
+
+    fn reload(&mut self, ctx: &Context<Self>) {
+        self.load_roots(ctx);
+        self.nodes.clear();
+        for node in self.expanded.values().cloned().collect::<Vec<_>>() {
+            self.load_children(ctx, &node);
+        }
+    }
+
+    /// Whether `candidate` is `node` itself or lies in the (loaded part of the) subtree of `node`.
+    fn is_in_subtree(&self, ctx: &Context<Self>, node: &str, candidate: &str) -> bool {
+        if node == candidate {
+            return true;
+        }
+        match self.nodes.get(node) {
+            Some(Ok(children)) => children
+                .iter()
+                .any(|child| self.is_in_subtree(ctx, &Self::key(ctx, child), candidate)),
+            _ => false,
+        }
+    }
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+    fn move_node(
+        &mut self,
+        ctx: &Context<Self>,
+        node: T::ReadModel,
+        new_parent: Option<T::ReadModel>,
+    ) {
+        let parent_field_name = ctx.props().parent_field.get_name();
+        let parent_field = match T::UpdateModel::get_all_fields()
+            .into_iter()
+            .find(|field| field.get_name() == parent_field_name)
+        {
+            Some(field) => field,
+            None => {
+                warn!("CrudTree: Cannot move node, as the UpdateModel has no field named '{parent_field_name}'.");
+                return;
+            }
+        };
+
+        let mut entity: T::UpdateModel = node.into();
+        let current = parent_field.get_value(&entity);
+        let value = match new_parent {
+            Some(parent) => as_parent_value(current, ctx.props().id_field.get_value(&parent)),
+            None => empty_parent_value(current),
+        };
+        parent_field.set_value(&mut entity, value);
+
+        let condition = <T as CrudMainTrait>::UpdateModelId::fields_iter(&entity.get_id())
+            .map(|field| (field.name().to_owned(), field.to_value()))
+            .into_all_equal_condition();
+        let data_provider = ctx.props().data_provider.clone();
+        self.ongoing_move = true;
+        ctx.link().send_future(async move {
+            Msg::Moved(
+                data_provider
+                    .update_one(UpdateOne {
+                        entity,
+                        condition: Some(condition),
+                    })
+                    .await,
+            )
+        });
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
-        false
+    fn render_nodes(&self, ctx: &Context<Self>, nodes: &[T::ReadModel], depth: usize) -> Html {
+        nodes
+            .iter()
+            .map(|node| {
+                let key = Self::key(ctx, node);
+                let expanded = self.expanded.contains_key(&key);
+                html! {
+                    <>
+                        { self.render_node(ctx, node, &key, depth, expanded) }
+                        if expanded {
+                            {
+                                match self.nodes.get(&key) {
+                                    Some(Ok(children)) => self.render_nodes(ctx, children, depth + 1),
+                                    Some(Err(NoData::NotYetLoaded)) | None => html! {},
+                                    Some(Err(reason)) => html! {
+                                        <tr>
+                                            <td colspan={"100%"}>
+                                                {format!("Keine Daten verfügbar: {reason:?}")}
+                                            </td>
+                                        </tr>
+                                    },
+                                }
+                            }
+                        }
+                    </>
+                }
+            })
+            .collect::<Html>()
     }
 
-    fn view(&self, _ctx: &Context<Self>) -> Html {
+    fn render_node(
+        &self,
+        ctx: &Context<Self>,
+        node: &T::ReadModel,
+        key: &str,
+        depth: usize,
+        expanded: bool,
+    ) -> Html {
+        let link = ctx.link();
+        let is_leaf = matches!(self.nodes.get(key), Some(Ok(children)) if children.is_empty());
+        let is_loading = matches!(self.nodes.get(key), Some(Err(NoData::NotYetLoaded)));
+        let is_drop_target = match &self.dragged {
+            Some(dragged) => !self.is_in_subtree(ctx, &Self::key(ctx, dragged), key),
+            None => false,
+        };
+
+        let toggled = node.clone();
+        let dragged = node.clone();
+        let dropped = node.clone();
+        let read = node.clone();
+        let edit = node.clone();
+        let delete = node.clone();
+        let read_allowed = ctx.props().allows(node, EntityOperation::Read);
+        let edit_allowed = ctx.props().allows(node, EntityOperation::Edit);
+        let delete_allowed = ctx.props().allows(node, EntityOperation::Delete);
+
         html! {
-            "test"
+            <tr
+                class={classes!("crud-tree-node", is_drop_target.then(|| "drop-target"))}
+                draggable={ctx.props().movable(node).then(|| "true")}
+                ondragstart={link.callback(move |_: DragEvent| Msg::DragStart(dragged.clone()))}
+                ondragend={link.callback(|_: DragEvent| Msg::DragEnd)}
+                ondragover={move |event: DragEvent| if is_drop_target { event.prevent_default() }}
+                ondrop={link.callback(move |event: DragEvent| {
+                    event.prevent_default();
+                    Msg::Drop(Some(dropped.clone()))
+                })}
+            >
+                {
+                    ctx.props().headers.iter().enumerate().map(|(index, (field, options))| {
+                        html! {
+                            <td>
+                                if index == 0 {
+                                    <div class={"crud-tree-indent"} style={format!("padding-left: {}em;", depth as f64 * 1.5)}>
+                                        <span
+                                            class={classes!("crud-tree-toggle", "interactable", is_leaf.then(|| "leaf"))}
+                                            onclick={link.callback({ let toggled = toggled.clone(); move |_| Msg::Toggle(toggled.clone()) })}
+                                        >
+                                            if is_loading {
+                                                <CrudIcon variant={Bi::HourglassSplit}/>
+                                            } else if is_leaf {
+                                                <CrudIcon variant={Bi::Dot}/>
+                                            } else if expanded {
+                                                <CrudIcon variant={Bi::ChevronDown}/>
+                                            } else {
+                                                <CrudIcon variant={Bi::ChevronRight}/>
+                                            }
+                                        </span>
+                                        { self.render_cell(ctx, node, field, options) }
+                                    </div>
+                                } else {
+                                    { self.render_cell(ctx, node, field, options) }
+                                }
+                            </td>
+                        }
+                    }).collect::<Html>()
+                }
+                if ctx.props().has_actions() {
+                    <td>
+                        <div class={"action-icons"}>
+                            if read_allowed {
+                                <div class={"action-icon"} onclick={link.callback(move |_| Msg::Read(read.clone()))}>
+                                    <CrudIcon variant={Bi::Eye}/>
+                                </div>
+                            }
+                            if edit_allowed {
+                                <div class={"action-icon"} onclick={link.callback(move |_| Msg::Edit(edit.clone()))}>
+                                    <CrudIcon variant={Bi::Pencil}/>
+                                </div>
+                            }
+                            if delete_allowed {
+                                <div class={"action-icon"} onclick={link.callback(move |_| Msg::Delete(delete.clone()))}>
+                                    <CrudIcon variant={Bi::Trash}/>
+                                </div>
+                            }
+                            {
+                                ctx.props().additional_item_actions.iter().map(|action| {
+                                    let cloned_action = action.clone();
+                                    let cloned_node = node.clone();
+                                    html! {
+                                        <div
+                                            class={"action-icon"}
+                                            onclick={link.callback(move |_| Msg::ActionTriggered((cloned_action.clone(), cloned_node.clone())))}>
+                                            <CrudIcon variant={action.get_icon().unwrap_or(Bi::Question)}/>
+                                        </div>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </div>
+                    </td>
+                }
+            </tr>
+        }
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &Context<Self>,
+        node: &T::ReadModel,
+        field: &<T::ReadModel as CrudDataTrait>::Field,
+        options: &HeaderOptions,
+    ) -> Html {
+        html! {
+            <CrudField<T::ReadModel>
+                children={ctx.props().children.clone()}
+                custom_fields={ctx.props().custom_fields.clone()}
+                api_base_url={ctx.props().api_base_url.clone()}
+                current_view={CrudSimpleView::List}
+                field_type={field.clone()}
+                field_options={FieldOptions { date_time_display: options.date_time_display, formatter: options.formatter.clone(), ..Default::default() }}
+                entity={node.clone()}
+                field_mode={FieldMode::Display}
+                value_changed={|_| {}}
+            />
+        }
+    }
+}
+
+impl<T: 'static + CrudMainTrait> Component for CrudTree<T> {
+    type Message = Msg<T>;
+    type Properties = Props<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let tree = Self {
+            roots: Err(NoData::NotYetLoaded),
+            nodes: HashMap::new(),
+            expanded: HashMap::new(),
+            dragged: None,
+            ongoing_move: false,
+        };
+        tree.load_roots(ctx);
+        tree
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if old_props.data_provider != ctx.props().data_provider
+            || old_props.root_condition != ctx.props().root_condition
+            || old_props.order_by != ctx.props().order_by
+        {
+            self.reload(ctx);
         }
+        true
     }
-}
\ No newline at end of file
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::RootsLoaded(result) => {
+                self.roots = result.map_err(NoData::FetchFailed);
+                true
+            }
+            Msg::ChildrenLoaded((key, result)) => {
+                self.nodes
+                    .insert(key, result.map_err(NoData::FetchFailed));
+                true
+            }
+            Msg::Toggle(node) => {
+                let key = Self::key(ctx, &node);
+                if self.expanded.remove(&key).is_none() {
+                    if !self.nodes.contains_key(&key) {
+                        self.load_children(ctx, &node);
+                    }
+                    self.expanded.insert(key, node);
+                }
+                true
+            }
+            Msg::Read(node) => {
+                ctx.props().on_read.emit(node);
+                false
+            }
+            Msg::Edit(node) => {
+                ctx.props().on_edit.emit(node);
+                false
+            }
+            Msg::Delete(node) => {
+                ctx.props().on_delete.emit(node);
+                false
+            }
+            Msg::ActionTriggered(action) => {
+                ctx.props().on_additional_item_action.emit(action);
+                false
+            }
+            Msg::DragStart(node) => {
+                self.dragged = Some(node);
+                true
+            }
+            Msg::DragEnd => {
+                self.dragged = None;
+                true
+            }
+            Msg::Drop(target) => {
+                if let Some(node) = self.dragged.take() {
+                    let allowed = match &target {
+                        Some(target) => !self.is_in_subtree(
+                            ctx,
+                            &Self::key(ctx, &node),
+                            &Self::key(ctx, target),
+                        ),
+                        None => true,
+                    };
+                    if allowed && !self.ongoing_move {
+                        self.move_node(ctx, node, target);
+                    }
+                }
+                true
+            }
+            Msg::Moved(result) => {
+                self.ongoing_move = false;
+                match result {
+                    Ok(SaveResult::Saved(saved)) => {
+                        ctx.props().on_moved.emit(saved.entity);
+                        self.reload(ctx);
+                    }
+                    Ok(SaveResult::Aborted { reason }) => {
+                        warn!("CrudTree: Moving the node was aborted: {reason}");
+                    }
+                    Ok(SaveResult::CriticalValidationErrors) => {
+                        warn!("CrudTree: Moving the node failed due to critical validation errors.");
+                    }
+                    Err(err) => {
+                        warn!("CrudTree: Moving the node failed: {err}");
+                    }
+                }
+                true
+            }
+            Msg::Reload => {
+                self.reload(ctx);
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class={"crud-table-wrapper crud-tree"}>
+                <table class={"crud-table crud-table-bordered crud-table-hoverable"}>
+                    <thead class={"crud-table-header"}>
+                        <tr>
+                            {
+                                ctx.props().headers.iter().map(|(_field, options)| html! {
+                                    <th class={classes!("crud-column-header", options.min_width.then(|| "min-width"))}>
+                                        {options.display_name.clone()}
+                                    </th>
+                                }).collect::<Html>()
+                            }
+                            if ctx.props().has_actions() {
+                                <th class={"actions min-width"}>
+                                    {"Aktionen"}
+                                </th>
+                            }
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {
+                            match &self.roots {
+                                Ok(roots) if roots.is_empty() => html! {
+                                    <tr>
+                                        <td colspan={"100%"} class={"no-data"}>
+                                            {"Keine Daten"}
+                                        </td>
+                                    </tr>
+                                },
+                                Ok(roots) => self.render_nodes(ctx, roots, 0),
+                                Err(NoData::NotYetLoaded) => html! {
+                                    <tr>
+                                        <td colspan={"100%"}>
+                                            {"\u{00a0}"}
+                                        </td>
+                                    </tr>
+                                },
+                                Err(reason) => html! {
+                                    <tr>
+                                        <td colspan={"100%"}>
+                                            {format!("Keine Daten verfügbar: {reason:?}")}
+                                        </td>
+                                    </tr>
+                                },
+                            }
+                        }
+                        if self.dragged.is_some() {
+                            <tr
+                                class={"crud-tree-root-drop-zone drop-target"}
+                                ondragover={|event: DragEvent| event.prevent_default()}
+                                ondrop={ctx.link().callback(|event: DragEvent| {
+                                    event.prevent_default();
+                                    Msg::Drop(None)
+                                })}
+                            >
+                                <td colspan={"100%"}>
+                                    {"Hier ablegen, um den Eintrag auf die oberste Ebene zu verschieben."}
+                                </td>
+                            </tr>
+                        }
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+}
+
+/// Converts the id of the new parent into a value suitable for the parent field, whose current value is given.
+fn as_parent_value(current: Value, parent_id: Value) -> Value {
+    match (current, parent_id) {
+        (Value::OptionalU32(_), Value::U32(id)) => Value::OptionalU32(Some(id)),
+        (Value::OptionalI32(_), Value::I32(id)) => Value::OptionalI32(Some(id)),
+        (Value::OptionalI64(_), Value::I64(id)) => Value::OptionalI64(Some(id)),
+        (Value::OneToOneRelation(_), Value::U32(id)) => Value::OneToOneRelation(Some(id)),
+        (_, parent_id) => parent_id,
+    }
+}
+
+/// A value for the parent field, whose current value is given, referencing no parent.
+fn empty_parent_value(current: Value) -> Value {
+    match current {
+        Value::OptionalU32(_) => Value::OptionalU32(None),
+        Value::OptionalI32(_) => Value::OptionalI32(None),
+        Value::OptionalI64(_) => Value::OptionalI64(None),
+        Value::OneToOneRelation(_) => Value::OneToOneRelation(None),
+        other => {
+            warn!("CrudTree: Parent field of type {other:?} can not be emptied. Value is kept.");
+            other
+        }
+    }
+}
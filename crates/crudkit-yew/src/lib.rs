@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use crudkit_condition::ConditionClauseValue;
 use crudkit_id::SerializableId;
 use dyn_clone::DynClone;
+use rust_decimal::prelude::ToPrimitive;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use services::requests::AuthProvider;
 use std::{
@@ -12,9 +13,9 @@ use std::{
     fmt::{Debug, Display},
     hash::Hash,
 };
-use time::format_description::well_known::Rfc3339;
+use time::{format_description::well_known::Rfc3339, macros::format_description};
 use tracing::warn;
-use types::RequestError;
+use types::{field_condition::FieldCondition, formatter::Formatter, validation::Validator, RequestError};
 use wasm_bindgen::JsCast;
 use web_sys::KeyboardEvent;
 use yew::prelude::*;
@@ -25,8 +26,11 @@ pub mod crud_btn;
 pub mod crud_btn_group;
 pub mod crud_btn_name;
 pub mod crud_btn_wrapper;
+pub mod crud_calendar;
+pub mod crud_card_grid;
 pub mod crud_checkbox;
 pub mod crud_collapsible;
+pub mod crud_column_chooser;
 pub mod crud_create_view;
 pub mod crud_delete_modal;
 pub mod crud_edit_view;
@@ -36,7 +40,9 @@ pub mod crud_fields;
 pub mod crud_icon;
 pub mod crud_image_chooser_modal;
 pub mod crud_image_gallery;
+pub mod crud_inline_edit_cell;
 pub mod crud_instance;
+pub mod crud_kanban;
 pub mod crud_leave_modal;
 pub mod crud_list_view;
 pub mod crud_modal;
@@ -52,6 +58,7 @@ pub mod crud_read_view;
 pub mod crud_related_field;
 pub mod crud_relation;
 pub mod crud_reset_field;
+pub mod crud_review_changes_modal;
 pub mod crud_safe_html;
 pub mod crud_select;
 pub mod crud_select_field;
@@ -63,6 +70,7 @@ pub mod crud_table_body;
 pub mod crud_table_footer;
 pub mod crud_table_header;
 pub mod crud_tabs;
+pub mod crud_tag_input;
 pub mod crud_tiptap_editor;
 pub mod crud_toast;
 pub mod crud_toasts;
@@ -95,6 +103,9 @@ pub use crudkit_websocket;
 // Reexporting yew_bootstrap_icons
 pub use yew_bootstrap_icons;
 
+// Reexporting rust_decimal, as models must use the exact `Decimal` type held by `Value::Decimal`.
+pub use rust_decimal;
+
 pub mod prelude {
     pub use crudkit_condition;
     pub use crudkit_id;
@@ -113,14 +124,18 @@ pub mod prelude {
     pub use super::crud_action::CrudActionAftermath;
     pub use super::crud_action::CrudActionTrait;
     pub use super::crud_action::CrudEntityAction;
+    pub use super::crud_action::EntityActionResult;
     pub use super::crud_action::States;
     pub use super::crud_alert::CrudAlert;
     pub use super::crud_btn::CrudBtn;
     pub use super::crud_btn_group::CrudBtnGroup;
     pub use super::crud_btn_name::CrudBtnName;
     pub use super::crud_btn_wrapper::CrudBtnWrapper;
+    pub use super::crud_calendar::CrudCalendar;
+    pub use super::crud_card_grid::CrudCardGrid;
     pub use super::crud_checkbox::CrudCheckbox;
     pub use super::crud_collapsible::CrudCollapsible;
+    pub use super::crud_column_chooser::CrudColumnChooser;
     pub use super::crud_create_view::CrudCreateView;
     pub use super::crud_delete_modal::CrudDeleteModal;
     pub use super::crud_edit_view::CrudEditView;
@@ -130,10 +145,16 @@ pub mod prelude {
     pub use super::crud_icon::CrudIcon;
     pub use super::crud_image_chooser_modal::CrudImageChooserModal;
     pub use super::crud_image_gallery::CrudImageGallery;
+    pub use super::crud_inline_edit_cell::CrudInlineEditCell;
     pub use super::crud_instance::CreateElements;
     pub use super::crud_instance::CrudInstance;
     pub use super::crud_instance::CrudInstanceConfig;
     pub use super::crud_instance::CrudStaticInstanceConfig;
+    pub use super::crud_instance::DetailElements;
+    pub use super::crud_instance::ListMode;
+    pub use super::crud_instance::ListPresentation;
+    pub use super::crud_kanban::CrudKanban;
+    pub use super::crud_kanban::KanbanColumn;
     pub use super::crud_leave_modal::CrudLeaveModal;
     pub use super::crud_list_view::CrudListView;
     pub use super::crud_modal::CrudModal;
@@ -147,6 +168,7 @@ pub mod prelude {
     pub use super::crud_related_field::CrudRelatedField;
     pub use super::crud_relation::CrudRelation;
     pub use super::crud_reset_field::CrudResetField;
+    pub use super::crud_review_changes_modal::CrudReviewChangesModal;
     pub use super::crud_safe_html::CrudSafeHtml;
     pub use super::crud_select::CrudSelect;
     pub use super::crud_select_field::CrudSelectField;
@@ -154,23 +176,46 @@ pub mod prelude {
     pub use super::crud_slider::CrudSlider;
     pub use super::crud_tab::CrudTab;
     pub use super::crud_table::CrudTable;
+    pub use super::crud_table::RowDetails;
     pub use super::crud_table_body::CrudTableBody;
     pub use super::crud_table_footer::CrudTableFooter;
     pub use super::crud_table_header::CrudTableHeader;
     pub use super::crud_tabs::CrudTabs;
+    pub use super::crud_tag_input::CrudTagInput;
     pub use super::crud_tiptap_editor::CrudTipTapEditor;
     pub use super::crud_toast::CrudToast;
     pub use super::crud_toasts::CrudToasts;
     pub use super::crud_toggle::{CrudToggle, CrudToggleIcons};
     pub use super::crud_tree::CrudTree;
+    pub use super::types::computed_field::ComputedCreateFields;
+    pub use super::types::computed_field::ComputedField;
+    pub use super::types::computed_field::ComputedFields;
+    pub use super::types::computed_field::ComputedReadFields;
+    pub use super::types::computed_field::ComputedUpdateFields;
     pub use super::types::custom_field::CustomCreateFields;
     pub use super::types::custom_field::CustomField;
     pub use super::types::custom_field::CustomFields;
     pub use super::types::custom_field::CustomReadFields;
     pub use super::types::custom_field::CustomUpdateFields;
+    pub use super::types::field_condition::FieldCondition;
+    pub use super::types::form_state::FormState;
+    pub use super::types::formatter::BoolFormat;
+    pub use super::types::formatter::Format;
+    pub use super::types::formatter::Formatter;
+    pub use super::types::formatter::NumberFormat;
+    pub use super::types::formatter::Separators;
+    pub use super::types::permissions::AllowAll;
+    pub use super::types::permissions::CrudPermissionPolicy;
+    pub use super::types::permissions::EntityOperation;
+    pub use super::types::permissions::FieldAccess;
+    pub use super::types::permissions::PermissionPolicy;
     pub use super::types::toasts::Toast;
     pub use super::types::toasts::ToastAutomaticallyClosing;
     pub use super::types::toasts::ToastVariant;
+    pub use super::types::validation::Validator;
+    pub use super::CalendarLayout;
+    pub use super::CalendarMode;
+    pub use super::CardLayout;
     pub use super::CrudActionPayload;
     pub use super::CrudDataTrait;
     pub use super::CrudFieldNameTrait;
@@ -190,12 +235,14 @@ pub mod prelude {
     pub use super::FieldOptions;
     pub use super::Group;
     pub use super::HeaderOptions;
+    pub use super::KanbanLayout;
     pub use super::Label;
     pub use super::Layout;
     pub use super::NoData;
     pub use super::OrderByUpdateOptions;
     pub use super::SerializableCrudView;
     pub use super::Tab;
+    pub use super::TableOptions;
     pub use super::Value;
     pub use super::Variant;
 }
@@ -299,6 +346,9 @@ pub trait CrudDataTrait: PartialEq + Clone + Debug + Serialize + DeserializeOwne
         + Send;
 
     fn get_field(field_name: &str) -> Self::Field;
+
+    /// All fields of this type, in declaration order.
+    fn get_all_fields() -> Vec<Self::Field>;
 }
 
 /// Allows us to access the ID of an entity.
@@ -344,12 +394,16 @@ pub trait Foo {
 // TODO: DEFERRED: Implement Serialize and Deserialize with typetag when wasm is supported in typetag. Comment in "typetag" occurrences.
 #[derive(Debug, Clone)]
 pub enum Value {
-    String(String),  // TODO: Add optional string!
-    Text(String),    // TODO: Add optional text!
+    String(String),
+    OptionalString(Option<String>),
+    Text(String),
+    OptionalText(Option<String>),
     Json(JsonValue), // TODO: Add optional json value
     OptionalJson(Option<JsonValue>),
-    UuidV4(uuid::Uuid), // TODO: Add optional UuidV4 value
-    UuidV7(uuid::Uuid), // TODO: Add optional UuidV7 value
+    UuidV4(uuid::Uuid),
+    OptionalUuidV4(Option<uuid::Uuid>),
+    UuidV7(uuid::Uuid),
+    OptionalUuidV7(Option<uuid::Uuid>),
     U32(u32),
     OptionalU32(Option<u32>),
     I32(i32),
@@ -357,6 +411,12 @@ pub enum Value {
     OptionalI32(Option<i32>),
     OptionalI64(Option<i64>),
     F32(f32),
+    F64(f64),
+    OptionalF32(Option<f32>),
+    OptionalF64(Option<f64>),
+    // Arbitrary-precision decimal number, e.g. for money. Never converted to a float, so that no precision is lost.
+    Decimal(rust_decimal::Decimal),
+    OptionalDecimal(Option<rust_decimal::Decimal>),
     Bool(bool),
     // Specialized bool-case, render as a green check mark if false and an orange exclamation mark if true.
     ValidationStatus(bool),
@@ -364,6 +424,16 @@ pub enum Value {
     OffsetDateTime(time::OffsetDateTime),
     OptionalPrimitiveDateTime(Option<time::PrimitiveDateTime>),
     OptionalOffsetDateTime(Option<time::OffsetDateTime>),
+    Date(time::Date),
+    OptionalDate(Option<time::Date>),
+    Time(time::Time),
+    OptionalTime(Option<time::Time>),
+    Duration(time::Duration),
+    OptionalDuration(Option<time::Duration>),
+    StringVec(Vec<String>),
+    I32Vec(Vec<i32>),
+    I64Vec(Vec<i64>),
+    UuidVec(Vec<uuid::Uuid>),
     OneToOneRelation(Option<u32>),
     NestedTable(Vec<Box<dyn crudkit_id::IdField>>),
     Custom(()),
@@ -423,7 +493,7 @@ impl Into<Value> for crudkit_shared::Value {
             crudkit_shared::Value::UuidV4(value) => Value::UuidV4(value),
             crudkit_shared::Value::UuidV7(value) => Value::UuidV7(value),
             crudkit_shared::Value::I32(value) => Value::I32(value),
-            crudkit_shared::Value::I32Vec(_values) => todo!("support vector types"),
+            crudkit_shared::Value::I32Vec(values) => Value::I32Vec(values),
             crudkit_shared::Value::I64(value) => Value::I64(value),
             crudkit_shared::Value::U32(value) => Value::U32(value),
             crudkit_shared::Value::F32(value) => Value::F32(value),
@@ -451,6 +521,12 @@ impl Into<Value> for crudkit_id::IdValue {
 }
 
 impl Value {
+    /// Whether both values are the same. `Value` can not implement `PartialEq`, as some variants hold trait objects.
+    /// Their debug representations are compared instead, which, unlike their display representations, never conflate `None` with a value.
+    pub fn is_same_as(&self, other: &Value) -> bool {
+        format!("{self:?}") == format!("{other:?}")
+    }
+
     pub fn take_string(self) -> String {
         match self {
             Self::String(string) => string,
@@ -463,6 +539,34 @@ impl Value {
             other => panic!("unsupported type provided: {other:?} "),
         }
     }
+    pub fn take_optional_string(self) -> Option<String> {
+        match self {
+            Self::String(string) => Some(string),
+            Self::OptionalString(optional_string) => optional_string,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_text(self) -> Option<String> {
+        match self {
+            Self::String(string) | Self::Text(string) => Some(string),
+            Self::OptionalText(optional_text) => optional_text,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_uuid_v4(self) -> Option<uuid::Uuid> {
+        match self {
+            Self::UuidV4(uuid) => Some(uuid),
+            Self::OptionalUuidV4(optional_uuid) => optional_uuid,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_uuid_v7(self) -> Option<uuid::Uuid> {
+        match self {
+            Self::UuidV7(uuid) => Some(uuid),
+            Self::OptionalUuidV7(optional_uuid) => optional_uuid,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
     pub fn take_inner_json_value(self) -> serde_json::Value {
         match self {
             Self::Json(json) => json.into(),
@@ -528,6 +632,48 @@ impl Value {
             other => panic!("unsupported type provided: {other:?} "),
         }
     }
+    pub fn take_f64(self) -> f64 {
+        match self {
+            Self::F64(f64) => f64,
+            Self::F32(f32) => f32 as f64,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_f32(self) -> Option<f32> {
+        match self {
+            Self::F32(value) => Some(value),
+            Self::OptionalF32(value) => value,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_f64(self) -> Option<f64> {
+        match self {
+            Self::F64(value) => Some(value),
+            Self::OptionalF64(value) => value,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_decimal(self) -> rust_decimal::Decimal {
+        match self {
+            Self::Decimal(value) => value,
+            Self::String(string) => string.parse().unwrap_or_else(|err| {
+                warn!("take_decimal could not parse string, using zero: {err}");
+                rust_decimal::Decimal::ZERO
+            }),
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_decimal(self) -> Option<rust_decimal::Decimal> {
+        match self {
+            Self::Decimal(value) => Some(value),
+            Self::OptionalDecimal(value) => value,
+            Self::String(string) => string
+                .parse::<rust_decimal::Decimal>()
+                .map_err(|err| warn!("take_optional_decimal could not parse string: {err}"))
+                .ok(),
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
     pub fn take_bool(self) -> bool {
         match self {
             Self::Bool(bool) => bool,
@@ -579,6 +725,73 @@ impl Value {
             other => panic!("unsupported type provided: {other:?} "),
         }
     }
+    pub fn take_date(self) -> time::Date {
+        match self {
+            Self::Date(date) => date,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_date(self) -> Option<time::Date> {
+        match self {
+            Self::Date(date) => Some(date),
+            Self::OptionalDate(optional_date) => optional_date,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_time(self) -> time::Time {
+        match self {
+            Self::Time(time) => time,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_time(self) -> Option<time::Time> {
+        match self {
+            Self::Time(time) => Some(time),
+            Self::OptionalTime(optional_time) => optional_time,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_duration(self) -> time::Duration {
+        match self {
+            Self::Duration(duration) => duration,
+            Self::String(string) => types::duration::parse_duration(&string).unwrap_or_else(|err| {
+                warn!("take_duration could not parse string, using zero: {err}");
+                time::Duration::ZERO
+            }),
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_optional_duration(self) -> Option<time::Duration> {
+        match self {
+            Self::Duration(duration) => Some(duration),
+            Self::OptionalDuration(optional_duration) => optional_duration,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_string_vec(self) -> Vec<String> {
+        match self {
+            Self::StringVec(values) => values,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_i32_vec(self) -> Vec<i32> {
+        match self {
+            Self::I32Vec(values) => values,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_i64_vec(self) -> Vec<i64> {
+        match self {
+            Self::I64Vec(values) => values,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
+    pub fn take_uuid_vec(self) -> Vec<uuid::Uuid> {
+        match self {
+            Self::UuidVec(values) => values,
+            other => panic!("unsupported type provided: {other:?} "),
+        }
+    }
     pub fn take_select(self) -> Box<dyn CrudSelectableTrait> {
         match self {
             Self::Select(selected) => selected,
@@ -632,20 +845,89 @@ impl Value {
             other => panic!("Expected Value of variant 'U32', 'OptionalU32' or 'OneToOneRelation'. Received: {other:?}"),
         }
     }
+
+    /// Whether this value holds nothing: `None`, an empty string or an empty selection.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Value::String(value) | Value::Text(value) => value.is_empty(),
+            Value::OptionalString(value) | Value::OptionalText(value) => {
+                value.as_ref().map_or(true, |it| it.is_empty())
+            }
+            Value::OptionalJson(value) => value.is_none(),
+            Value::OptionalUuidV4(value) | Value::OptionalUuidV7(value) => value.is_none(),
+            Value::OptionalU32(value) => value.is_none(),
+            Value::OptionalI32(value) => value.is_none(),
+            Value::OptionalI64(value) => value.is_none(),
+            Value::OptionalF32(value) => value.is_none(),
+            Value::OptionalF64(value) => value.is_none(),
+            Value::OptionalDecimal(value) => value.is_none(),
+            Value::OptionalPrimitiveDateTime(value) => value.is_none(),
+            Value::OptionalOffsetDateTime(value) => value.is_none(),
+            Value::OptionalDate(value) => value.is_none(),
+            Value::OptionalTime(value) => value.is_none(),
+            Value::OptionalDuration(value) => value.is_none(),
+            Value::OneToOneRelation(value) => value.is_none(),
+            Value::StringVec(value) => value.is_empty(),
+            Value::I32Vec(value) => value.is_empty(),
+            Value::I64Vec(value) => value.is_empty(),
+            Value::UuidVec(value) => value.is_empty(),
+            Value::Multiselect(value) => value.is_empty(),
+            Value::OptionalSelect(value) => value.is_none(),
+            Value::OptionalMultiselect(value) => value.as_ref().map_or(true, |it| it.is_empty()),
+            _ => false,
+        }
+    }
+
+    /// The numeric value held, if this is a (present) number.
+    /// Decimals are converted with a possible loss of precision.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::U32(value) | Value::OptionalU32(Some(value)) => Some(*value as f64),
+            Value::I32(value) | Value::OptionalI32(Some(value)) => Some(*value as f64),
+            Value::I64(value) | Value::OptionalI64(Some(value)) => Some(*value as f64),
+            Value::F32(value) | Value::OptionalF32(Some(value)) => Some(*value as f64),
+            Value::F64(value) | Value::OptionalF64(Some(value)) => Some(*value),
+            Value::Decimal(value) | Value::OptionalDecimal(Some(value)) => value.to_f64(),
+            _ => None,
+        }
+    }
+}
+
+const ISO_DATE: &[time::format_description::FormatItem<'static>] = format_description!("[year]-[month]-[day]");
+const ISO_TIME: &[time::format_description::FormatItem<'static>] = format_description!("[hour]:[minute]:[second]");
+
+fn join<V: ToString>(values: &[V]) -> String {
+    values.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
 }
 
 impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Value::String(value) => f.write_str(value),
+            Value::OptionalString(value) => match value {
+                Some(value) => f.write_str(value),
+                None => f.write_str("-"),
+            },
             Value::Text(value) => f.write_str(value),
+            Value::OptionalText(value) => match value {
+                Some(value) => f.write_str(value),
+                None => f.write_str("-"),
+            },
             Value::Json(value) => f.write_str(value.get_string_representation()),
             Value::OptionalJson(value) => match value {
                 Some(value) => f.write_str(value.get_string_representation()),
                 None => f.write_str("-"),
             },
             Value::UuidV4(value) => f.write_str(&value.to_string()),
+            Value::OptionalUuidV4(value) => match value {
+                Some(value) => f.write_str(&value.to_string()),
+                None => f.write_str("-"),
+            },
             Value::UuidV7(value) => f.write_str(&value.to_string()),
+            Value::OptionalUuidV7(value) => match value {
+                Some(value) => f.write_str(&value.to_string()),
+                None => f.write_str("-"),
+            },
             Value::U32(value) => f.write_str(&value.to_string()),
             Value::OptionalU32(value) => match value {
                 Some(value) => f.write_str(&value.to_string()),
@@ -662,6 +944,20 @@ impl Display for Value {
                 None => f.write_str("-"),
             },
             Value::F32(value) => f.write_str(&value.to_string()),
+            Value::F64(value) => f.write_str(&value.to_string()),
+            Value::OptionalF32(value) => match value {
+                Some(value) => f.write_str(&value.to_string()),
+                None => f.write_str("-"),
+            },
+            Value::OptionalF64(value) => match value {
+                Some(value) => f.write_str(&value.to_string()),
+                None => f.write_str("-"),
+            },
+            Value::Decimal(value) => f.write_str(&value.to_string()),
+            Value::OptionalDecimal(value) => match value {
+                Some(value) => f.write_str(&value.to_string()),
+                None => f.write_str("-"),
+            },
             Value::Bool(value) => f.write_str(&value.to_string()),
             Value::ValidationStatus(value) => f.write_str(&value.to_string()),
             Value::PrimitiveDateTime(value) => f.write_str(&value.format(&Rfc3339).unwrap()),
@@ -674,10 +970,29 @@ impl Display for Value {
                 Some(value) => f.write_str(&value.format(&Rfc3339).unwrap()),
                 None => f.write_str(""),
             },
+            Value::Date(value) => f.write_str(&value.format(ISO_DATE).unwrap()),
+            Value::OptionalDate(value) => match value {
+                Some(value) => f.write_str(&value.format(ISO_DATE).unwrap()),
+                None => f.write_str(""),
+            },
+            Value::Time(value) => f.write_str(&value.format(ISO_TIME).unwrap()),
+            Value::OptionalTime(value) => match value {
+                Some(value) => f.write_str(&value.format(ISO_TIME).unwrap()),
+                None => f.write_str(""),
+            },
+            Value::Duration(value) => f.write_str(&types::duration::format_duration(value)),
+            Value::OptionalDuration(value) => match value {
+                Some(value) => f.write_str(&types::duration::format_duration(value)),
+                None => f.write_str(""),
+            },
             Value::OneToOneRelation(value) => match value {
                 Some(value) => f.write_str(&value.to_string()),
                 None => f.write_str(""),
             },
+            Value::StringVec(values) => f.write_str(&values.join(", ")),
+            Value::I32Vec(values) => f.write_str(&join(values)),
+            Value::I64Vec(values) => f.write_str(&join(values)),
+            Value::UuidVec(values) => f.write_str(&join(values)),
             Value::NestedTable(id) => {
                 for field in id {
                     f.write_fmt(format_args!(
@@ -713,16 +1028,30 @@ impl Display for Value {
     }
 }
 
+/// The condition value matching an absent value, i.e. an optional field set to `None`.
+/// Condition values have no dedicated null variant, so a JSON null is used instead.
+pub fn null_condition_value() -> ConditionClauseValue {
+    ConditionClauseValue::Json(serde_json::Value::Null)
+}
+
 impl Into<ConditionClauseValue> for Value {
     fn into(self) -> ConditionClauseValue {
         match self {
             // TODO: Complete mapping!!
             Value::String(value) => ConditionClauseValue::String(value),
+            Value::OptionalString(Some(value)) => ConditionClauseValue::String(value),
+            Value::OptionalString(None) => null_condition_value(),
             Value::Text(value) => ConditionClauseValue::String(value),
+            Value::OptionalText(Some(value)) => ConditionClauseValue::String(value),
+            Value::OptionalText(None) => null_condition_value(),
             Value::Json(value) => ConditionClauseValue::Json(value.into()),
             Value::OptionalJson(value) => todo!(),
             Value::UuidV4(value) => ConditionClauseValue::UuidV4(value),
+            Value::OptionalUuidV4(Some(value)) => ConditionClauseValue::UuidV4(value),
+            Value::OptionalUuidV4(None) => null_condition_value(),
             Value::UuidV7(value) => ConditionClauseValue::UuidV7(value),
+            Value::OptionalUuidV7(Some(value)) => ConditionClauseValue::UuidV7(value),
+            Value::OptionalUuidV7(None) => null_condition_value(),
             Value::U32(value) => ConditionClauseValue::U32(value),
             Value::OptionalU32(value) => todo!(),
             Value::I32(value) => ConditionClauseValue::I32(value),
@@ -730,12 +1059,37 @@ impl Into<ConditionClauseValue> for Value {
             Value::OptionalI32(value) => todo!(),
             Value::OptionalI64(value) => todo!(),
             Value::F32(value) => ConditionClauseValue::F32(value),
+            Value::F64(value) => ConditionClauseValue::F64(value),
+            Value::OptionalF32(Some(value)) => ConditionClauseValue::F32(value),
+            Value::OptionalF32(None) => null_condition_value(),
+            Value::OptionalF64(Some(value)) => ConditionClauseValue::F64(value),
+            Value::OptionalF64(None) => null_condition_value(),
+            // Decimals are passed as their string representation, so that the backend can compare them exactly.
+            Value::Decimal(value) => ConditionClauseValue::String(value.to_string()),
+            Value::OptionalDecimal(Some(value)) => ConditionClauseValue::String(value.to_string()),
+            Value::OptionalDecimal(None) => null_condition_value(),
             Value::Bool(value) => ConditionClauseValue::Bool(value),
             Value::ValidationStatus(value) => todo!(),
             Value::PrimitiveDateTime(value) => todo!(),
             Value::OffsetDateTime(value) => todo!(),
             Value::OptionalPrimitiveDateTime(value) => todo!(),
             Value::OptionalOffsetDateTime(value) => todo!(),
+            // Dates and times are passed in the ISO 8601 representation produced by `Display`.
+            Value::Date(_) | Value::OptionalDate(Some(_)) | Value::Time(_) | Value::OptionalTime(Some(_)) => {
+                ConditionClauseValue::String(self.to_string())
+            }
+            Value::OptionalDate(None) | Value::OptionalTime(None) => null_condition_value(),
+            // Durations are passed as their number of whole seconds.
+            Value::Duration(value) => ConditionClauseValue::I64(value.whole_seconds()),
+            Value::OptionalDuration(Some(value)) => ConditionClauseValue::I64(value.whole_seconds()),
+            Value::OptionalDuration(None) => null_condition_value(),
+            // Lists are passed as JSON arrays, to be matched like an SQL "IN" clause.
+            Value::StringVec(values) => ConditionClauseValue::Json(values.into()),
+            Value::I32Vec(values) => ConditionClauseValue::Json(values.into()),
+            Value::I64Vec(values) => ConditionClauseValue::Json(values.into()),
+            Value::UuidVec(values) => {
+                ConditionClauseValue::Json(values.iter().map(ToString::to_string).collect::<Vec<_>>().into())
+            }
             Value::OneToOneRelation(value) => todo!(),
             Value::NestedTable(value) => todo!(),
             Value::Custom(value) => todo!(),
@@ -765,6 +1119,18 @@ pub trait CrudFieldNameTrait {
 pub trait CrudFieldValueTrait<T> {
     fn get_value(&self, entity: &T) -> Value;
     fn set_value(&self, entity: &mut T, value: Value);
+
+    /// Validators declared on the field, e.g. using `#[ck_field_value(validate(required, min_len = 3))]`.
+    fn validators(&self) -> Vec<Validator> {
+        Vec::new()
+    }
+
+    /// Checks the given value against all validators of this field. Returns the first violation.
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        self.validators()
+            .iter()
+            .try_for_each(|validator| validator.validate(value))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -862,6 +1228,16 @@ pub struct HeaderOptions {
     pub min_width: bool,
     pub ordering_allowed: bool,
     pub date_time_display: DateTimeDisplay,
+    /// Width of the column in pixels. The column is sized automatically if `None`. Set when the user resizes a column.
+    #[serde(default)]
+    pub width: Option<u32>,
+    /// Keep this column visible when scrolling horizontally.
+    /// Only takes effect for columns at the start of the table, without a non-sticky column in front of them.
+    #[serde(default)]
+    pub sticky: bool,
+    /// How the values of this column are displayed.
+    #[serde(default)]
+    pub formatter: Formatter,
 }
 
 // TODO: we might want to use the builder pattern instead of relying on ..Default.default()
@@ -872,10 +1248,82 @@ impl Default for HeaderOptions {
             min_width: false,
             ordering_allowed: true,
             date_time_display: DateTimeDisplay::LocalizedLocal,
+            width: None,
+            sticky: false,
+            formatter: Formatter::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableOptions {
+    /// Keep the header row visible when scrolling vertically.
+    pub sticky_header: bool,
+    /// Keep the row actions visible when scrolling horizontally.
+    pub sticky_actions: bool,
+    /// Allow editing a cell in place by double-clicking it.
+    /// Clicking a row no longer opens the edit view when enabled.
+    #[serde(default)]
+    pub inline_edit: bool,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            sticky_header: true,
+            sticky_actions: false,
+            inline_edit: false,
         }
     }
 }
 
+/// Which fields of an entity are shown on its card when the list is presented as cards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardLayout<T: CrudDataTrait> {
+    pub title: T::Field,
+    #[serde(default)]
+    pub subtitles: Vec<T::Field>,
+    #[serde(default)]
+    pub image: Option<T::Field>,
+}
+
+/// The time span shown at once by a `CrudCalendar`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CalendarMode {
+    Month,
+    Week,
+    Day,
+}
+
+impl Default for CalendarMode {
+    fn default() -> Self {
+        Self::Month
+    }
+}
+
+/// Which fields of an entity place it in a calendar. `start` and `end` must hold `OffsetDateTime` or `PrimitiveDateTime` values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarLayout<T: CrudDataTrait> {
+    pub start: T::Field,
+    /// Entities without an end are shown on the day they start.
+    #[serde(default)]
+    pub end: Option<T::Field>,
+    pub title: T::Field,
+    #[serde(default)]
+    pub mode: CalendarMode,
+}
+
+/// Which fields of an entity are used when the list is presented as a kanban board.
+/// The columns of the board are configured by `CrudStaticInstanceConfig::kanban_columns`, as their values can not be serialized.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KanbanLayout<T: CrudDataTrait> {
+    /// The field by whose value entities are grouped into columns.
+    pub group: T::Field,
+    pub title: T::Field,
+    #[serde(default)]
+    pub fields: Vec<T::Field>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum DateTimeDisplay {
     IsoUtc,
@@ -898,6 +1346,15 @@ pub struct FieldOptions {
     pub disabled: bool,
     pub label: Option<Label>,
     pub date_time_display: DateTimeDisplay,
+    /// How the value is displayed when the field is rendered in `FieldMode::Display`.
+    #[serde(default)]
+    pub formatter: Formatter,
+    /// The field is only shown while this condition holds for the current input.
+    #[serde(default)]
+    pub visible_if: Option<FieldCondition>,
+    /// The field is disabled unless this condition holds for the current input.
+    #[serde(default)]
+    pub enabled_if: Option<FieldCondition>,
     //validations: Vec<u32>,
 }
 
@@ -907,6 +1364,9 @@ impl Default for FieldOptions {
             disabled: false,
             label: None,
             date_time_display: DateTimeDisplay::LocalizedLocal,
+            formatter: Formatter::default(),
+            visible_if: None,
+            enabled_if: None,
         }
     }
 }
@@ -962,6 +1422,12 @@ pub struct Group<T: CrudDataTrait> {
     // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
     #[serde(bound = "")]
     pub children: Vec<Elem<T>>,
+    /// The group and all its children are only shown while this condition holds for the current input.
+    #[serde(default)]
+    pub visible_if: Option<FieldCondition>,
+    /// All children of the group are disabled unless this condition holds for the current input.
+    #[serde(default)]
+    pub enabled_if: Option<FieldCondition>,
 }
 
 pub fn event_target_as<T: JsCast>(event: Event) -> Result<T, String> {
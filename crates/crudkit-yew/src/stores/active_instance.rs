@@ -0,0 +1,31 @@
+use yewdux::prelude::*;
+
+/// Name of the instance the user interacted with last. Keyboard shortcuts only apply to this instance,
+/// so that pages showing several instances do not react to a shortcut multiple times.
+#[derive(Clone, Default, PartialEq, Eq, Store)]
+pub struct ActiveInstance {
+    name: Option<String>,
+}
+
+impl ActiveInstance {
+    pub fn is_active(&self, instance_name: &str) -> bool {
+        self.name.as_deref() == Some(instance_name)
+    }
+
+    pub fn activate(&mut self, instance_name: String) {
+        self.name = Some(instance_name);
+    }
+
+    /// Makes the given instance the active one, unless another instance already is.
+    pub fn activate_if_none(&mut self, instance_name: String) {
+        if self.name.is_none() {
+            self.name = Some(instance_name);
+        }
+    }
+
+    pub fn deactivate(&mut self, instance_name: &str) {
+        if self.is_active(instance_name) {
+            self.name = None;
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{macros::format_description, OffsetDateTime, UtcOffset};
+use yewdux::prelude::*;
+
+use crate::CrudMainTrait;
+
+/// Unsaved inputs of create and edit views. Kept in the local storage, so that they survive a crashed or closed tab.
+#[derive(Default, Clone, PartialEq, Debug, Serialize, Deserialize, Store)]
+#[store(storage = "local", storage_tab_sync)]
+pub struct DraftsStore<T: CrudMainTrait + 'static> {
+    /// Drafts of create views, by instance name.
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(bound = "")]
+    create_drafts: HashMap<String, Draft<T::CreateModel>>,
+    /// Drafts of edit views, by instance name and the id of the edited entity.
+    // serde bound used as described in: https://github.com/serde-rs/serde/issues/1296
+    #[serde(bound = "")]
+    edit_drafts: HashMap<String, Draft<T::UpdateModel>>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Draft<M> {
+    pub input: M,
+    /// Unix timestamp of the moment this draft was saved, in seconds.
+    pub saved_at: i64,
+}
+
+impl<M> Draft<M> {
+    pub fn new(input: M) -> Self {
+        Self {
+            input,
+            saved_at: OffsetDateTime::now_utc().unix_timestamp(),
+        }
+    }
+
+    /// The moment this draft was saved, formatted for the user.
+    pub fn saved_at_localized(&self) -> String {
+        OffsetDateTime::from_unix_timestamp(self.saved_at)
+            .ok()
+            .map(|saved_at| match UtcOffset::current_local_offset() {
+                Ok(offset) => saved_at.to_offset(offset),
+                Err(_) => saved_at,
+            })
+            .and_then(|saved_at| {
+                saved_at
+                    .format(format_description!("[day].[month].[year] [hour]:[minute]"))
+                    .ok()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl<T: CrudMainTrait> DraftsStore<T> {
+    pub fn get_create_draft(&self, instance_name: &str) -> Option<Draft<T::CreateModel>> {
+        self.create_drafts.get(instance_name).cloned()
+    }
+
+    pub fn save_create_draft(&mut self, instance_name: String, draft: Draft<T::CreateModel>) {
+        self.create_drafts.insert(instance_name, draft);
+    }
+
+    pub fn remove_create_draft(&mut self, instance_name: &str) {
+        self.create_drafts.remove(instance_name);
+    }
+
+    pub fn get_edit_draft(&self, instance_name: &str, id: &T::UpdateModelId) -> Option<Draft<T::UpdateModel>> {
+        self.edit_drafts.get(&edit_key(instance_name, id)).cloned()
+    }
+
+    pub fn save_edit_draft(&mut self, instance_name: &str, id: &T::UpdateModelId, draft: Draft<T::UpdateModel>) {
+        self.edit_drafts.insert(edit_key(instance_name, id), draft);
+    }
+
+    pub fn remove_edit_draft(&mut self, instance_name: &str, id: &T::UpdateModelId) {
+        self.edit_drafts.remove(&edit_key(instance_name, id));
+    }
+}
+
+fn edit_key<Id: Serialize>(instance_name: &str, id: &Id) -> String {
+    let id = serde_json::to_string(id).unwrap_or_default();
+    format!("{instance_name}/{id}")
+}
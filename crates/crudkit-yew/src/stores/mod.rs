@@ -1,3 +1,5 @@
+pub mod active_instance;
+pub mod drafts;
 pub mod instance;
 pub mod instance_links;
 pub mod instance_views;
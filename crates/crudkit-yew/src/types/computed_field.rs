@@ -0,0 +1,95 @@
+use std::{fmt::Debug, rc::Rc};
+
+use indexmap::IndexMap;
+
+use crate::{CrudDataTrait, CrudFieldValueTrait, CrudMainTrait, Elem, Enclosing, Group, Tab, Value};
+
+/// Computes the value of a field from the other fields of the same entity, e.g. `total = quantity * unit_price`.
+/// Computed fields are recomputed whenever the user changes a value in the create or edit view and are always read-only.
+#[derive(Clone)]
+pub struct ComputedField<T: CrudDataTrait> {
+    pub compute: Rc<dyn Fn(&T) -> Value>,
+}
+
+impl<T: CrudDataTrait> ComputedField<T> {
+    pub fn new(compute: impl Fn(&T) -> Value + 'static) -> Self {
+        Self {
+            compute: Rc::new(compute),
+        }
+    }
+
+    pub fn compute(&self, entity: &T) -> Value {
+        (self.compute)(entity)
+    }
+}
+
+impl<T: CrudDataTrait> Debug for ComputedField<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputedField").finish()
+    }
+}
+
+impl<T: CrudDataTrait> PartialEq for ComputedField<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.compute, &other.compute)
+    }
+}
+
+/// Computed fields are applied in insertion order, so that a computation may use the result of an earlier one.
+pub type ComputedFields<T> = IndexMap<<T as CrudDataTrait>::Field, ComputedField<T>>;
+pub type ComputedCreateFields<T> = ComputedFields<<T as CrudMainTrait>::CreateModel>;
+pub type ComputedUpdateFields<T> = ComputedFields<<T as CrudMainTrait>::UpdateModel>;
+pub type ComputedReadFields<T> = ComputedFields<<T as CrudMainTrait>::ReadModel>;
+
+/// Sets all computed fields of the given entity to their current value.
+/// Returns whether the value of any computed field changed.
+pub fn apply<T: CrudDataTrait>(computed_fields: &ComputedFields<T>, entity: &mut T) -> bool {
+    let mut changed = false;
+    for (field, computed_field) in computed_fields {
+        let value = computed_field.compute(entity);
+        changed |= !field.get_value(entity).is_same_as(&value);
+        field.set_value(entity, value);
+    }
+    changed
+}
+
+/// Disables all computed fields in the given layout, as their values can not be edited by the user.
+pub fn disable_computed<T: CrudDataTrait>(
+    computed_fields: &ComputedFields<T>,
+    elements: Vec<Elem<T>>,
+) -> Vec<Elem<T>> {
+    if computed_fields.is_empty() {
+        return elements;
+    }
+    elements
+        .into_iter()
+        .map(|elem| match elem {
+            Elem::Enclosing(Enclosing::None(group)) => {
+                Elem::Enclosing(Enclosing::None(disable_computed_in_group(computed_fields, group)))
+            }
+            Elem::Enclosing(Enclosing::Tabs(tabs)) => Elem::Enclosing(Enclosing::Tabs(
+                tabs.into_iter()
+                    .map(|tab| Tab {
+                        label: tab.label,
+                        group: disable_computed_in_group(computed_fields, tab.group),
+                    })
+                    .collect(),
+            )),
+            Elem::Enclosing(Enclosing::Card(group)) => {
+                Elem::Enclosing(Enclosing::Card(disable_computed_in_group(computed_fields, group)))
+            }
+            Elem::Field((field, mut options)) => {
+                options.disabled |= computed_fields.contains_key(&field);
+                Elem::Field((field, options))
+            }
+            Elem::Separator => Elem::Separator,
+        })
+        .collect()
+}
+
+fn disable_computed_in_group<T: CrudDataTrait>(computed_fields: &ComputedFields<T>, group: Group<T>) -> Group<T> {
+    Group {
+        children: disable_computed(computed_fields, group.children),
+        ..group
+    }
+}
@@ -0,0 +1,162 @@
+use thiserror::Error as ThisError;
+
+/// Units understood when parsing a duration, with their length in seconds.
+const UNITS: [(&str, i64); 4] = [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)];
+
+#[derive(ThisError, Clone, Debug, PartialEq)]
+pub enum ParseDurationError {
+    #[error("Expected a number in duration '{0}'.")]
+    MissingNumber(String),
+
+    #[error("Unknown unit '{0}'. Expected one of: d, h, m, s.")]
+    UnknownUnit(String),
+
+    #[error("Duration '{0}' is too large.")]
+    Overflow(String),
+}
+
+/// Formats the duration in a human-friendly way, as in "1d 2h 30m". Parts which are zero are left out.
+pub fn format_duration(duration: &time::Duration) -> String {
+    let sign = match duration.is_negative() {
+        true => "-",
+        false => "",
+    };
+    let mut seconds = duration.whole_seconds().abs();
+    let mut parts = Vec::new();
+    for (unit, length) in UNITS {
+        let amount = seconds / length;
+        seconds %= length;
+        if amount > 0 {
+            parts.push(format!("{amount}{unit}"));
+        }
+    }
+    match parts.is_empty() {
+        true => "0m".to_owned(),
+        false => format!("{sign}{}", parts.join(" ")),
+    }
+}
+
+/// Parses human-friendly durations like "1h 30m", "2d", "90m" or "1h30m".
+/// A number without a unit is interpreted as minutes. A leading "-" negates the duration.
+pub fn parse_duration(input: &str) -> Result<time::Duration, ParseDurationError> {
+    let input = input.trim();
+    let (negative, input) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+
+    if input.trim().is_empty() {
+        return Err(ParseDurationError::MissingNumber(input.to_owned()));
+    }
+
+    let mut seconds = 0i64;
+    let mut rest = input.trim_start();
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(ParseDurationError::MissingNumber(input.to_owned()));
+        }
+        let amount = rest[..digits]
+            .parse::<i64>()
+            .map_err(|_| ParseDurationError::Overflow(input.to_owned()))?;
+        rest = rest[digits..].trim_start();
+
+        let letters = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+        let unit = rest[..letters].to_lowercase();
+        let length = match unit.as_str() {
+            "" => 60,
+            unit => UNITS
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, length)| *length)
+                .ok_or_else(|| ParseDurationError::UnknownUnit(unit.to_owned()))?,
+        };
+        seconds = amount
+            .checked_mul(length)
+            .and_then(|part| seconds.checked_add(part))
+            .ok_or_else(|| ParseDurationError::Overflow(input.to_owned()))?;
+        rest = rest[letters..].trim_start();
+    }
+
+    Ok(time::Duration::seconds(match negative {
+        true => -seconds,
+        false => seconds,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Duration;
+
+    #[test]
+    fn parses_combined_units() {
+        assert_eq!(parse_duration("1h 30m"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration("1D 2H"), Ok(Duration::hours(26)));
+    }
+
+    #[test]
+    fn parses_bare_number_as_minutes() {
+        assert_eq!(parse_duration("90"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration(" 5 "), Ok(Duration::minutes(5)));
+    }
+
+    #[test]
+    fn parses_negative_duration() {
+        assert_eq!(parse_duration("-2d"), Ok(Duration::days(-2)));
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(parse_duration(""), Err(ParseDurationError::MissingNumber(String::new())));
+        assert_eq!(parse_duration("-"), Err(ParseDurationError::MissingNumber(String::new())));
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        assert_eq!(parse_duration("1x"), Err(ParseDurationError::UnknownUnit("x".to_owned())));
+    }
+
+    #[test]
+    fn rejects_unit_without_number() {
+        assert_eq!(parse_duration("h"), Err(ParseDurationError::MissingNumber("h".to_owned())));
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert_eq!(
+            parse_duration("9223372036854775807d"),
+            Err(ParseDurationError::Overflow("9223372036854775807d".to_owned()))
+        );
+        assert_eq!(
+            parse_duration("99999999999999999999"),
+            Err(ParseDurationError::Overflow("99999999999999999999".to_owned()))
+        );
+        assert_eq!(
+            parse_duration("9223372036854775807s 1s"),
+            Err(ParseDurationError::Overflow("9223372036854775807s 1s".to_owned()))
+        );
+    }
+
+    #[test]
+    fn formats_duration() {
+        assert_eq!(format_duration(&Duration::minutes(90)), "1h 30m");
+        assert_eq!(format_duration(&Duration::seconds(90_061)), "1d 1h 1m 1s");
+        assert_eq!(format_duration(&Duration::days(-2)), "-2d");
+        assert_eq!(format_duration(&Duration::ZERO), "0m");
+    }
+
+    #[test]
+    fn round_trips() {
+        for duration in [
+            Duration::ZERO,
+            Duration::seconds(1),
+            Duration::minutes(90),
+            Duration::hours(-26),
+            Duration::seconds(90_061),
+        ] {
+            assert_eq!(parse_duration(&format_duration(&duration)), Ok(duration));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{CrudDataTrait, CrudFieldNameTrait, CrudFieldValueTrait, Elem, Enclosing, Group, Value};
+
+/// A condition on the current input of a form, deciding whether a field or group is shown or enabled.
+/// Fields are referenced by name and compared by their displayed value, so that layouts stay serializable.
+///
+/// Example: Show `port` only if `protocol` is TCP:
+/// `FieldOptions { visible_if: Some(FieldCondition::equals("protocol", "TCP")), ..Default::default() }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FieldCondition {
+    /// The value of the field, as displayed, equals `value`. Booleans are displayed as "true" or "false".
+    Equals { field: String, value: String },
+    /// The value of the field, as displayed, equals one of `values`.
+    OneOf { field: String, values: Vec<String> },
+    /// The field holds a value. See `Value::is_empty`.
+    NotEmpty { field: String },
+    Not(Box<FieldCondition>),
+    All(Vec<FieldCondition>),
+    Any(Vec<FieldCondition>),
+}
+
+impl FieldCondition {
+    pub fn equals(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Equals {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn is_true(field: impl Into<String>) -> Self {
+        Self::equals(field, "true")
+    }
+
+    pub fn not_empty(field: impl Into<String>) -> Self {
+        Self::NotEmpty {
+            field: field.into(),
+        }
+    }
+
+    pub fn evaluate<T: CrudDataTrait>(&self, entity: &T) -> bool {
+        match self {
+            FieldCondition::Equals { field, value } => {
+                value_of(field, entity).map_or(false, |it| &it.to_string() == value)
+            }
+            FieldCondition::OneOf { field, values } => value_of(field, entity)
+                .map_or(false, |it| values.contains(&it.to_string())),
+            FieldCondition::NotEmpty { field } => value_of(field, entity).map_or(false, |it| !it.is_empty()),
+            FieldCondition::Not(condition) => !condition.evaluate(entity),
+            FieldCondition::All(conditions) => conditions.iter().all(|it| it.evaluate(entity)),
+            FieldCondition::Any(conditions) => conditions.iter().any(|it| it.evaluate(entity)),
+        }
+    }
+}
+
+fn value_of<T: CrudDataTrait>(field_name: &str, entity: &T) -> Option<Value> {
+    let field = T::get_all_fields()
+        .into_iter()
+        .find(|field| field.get_name() == field_name);
+    if field.is_none() {
+        warn!("FieldCondition references the unknown field '{field_name}'.");
+    }
+    field.map(|field| field.get_value(entity))
+}
+
+/// Whether an optional condition holds. Missing conditions always hold.
+pub fn holds<T: CrudDataTrait>(condition: &Option<FieldCondition>, entity: &T) -> bool {
+    condition.as_ref().map_or(true, |it| it.evaluate(entity))
+}
+
+/// The results of all conditions in the given layout, in layout order.
+/// Comparing the results before and after an input change tells whether the form must be rendered again.
+pub fn evaluate_conditions<T: CrudDataTrait>(elements: &[Elem<T>], entity: &T) -> Vec<bool> {
+    let mut results = Vec::new();
+    collect_results(elements, entity, &mut results);
+    results
+}
+
+fn collect_results<T: CrudDataTrait>(elements: &[Elem<T>], entity: &T, results: &mut Vec<bool>) {
+    let collect_group = |group: &Group<T>, results: &mut Vec<bool>| {
+        results.push(holds(&group.visible_if, entity));
+        results.push(holds(&group.enabled_if, entity));
+        collect_results(&group.children, entity, results);
+    };
+    for elem in elements {
+        match elem {
+            Elem::Enclosing(Enclosing::None(group)) | Elem::Enclosing(Enclosing::Card(group)) => {
+                collect_group(group, results)
+            }
+            Elem::Enclosing(Enclosing::Tabs(tabs)) => {
+                for tab in tabs {
+                    collect_group(&tab.group, results);
+                }
+            }
+            Elem::Field((_field, options)) => {
+                results.push(holds(&options.visible_if, entity));
+                results.push(holds(&options.enabled_if, entity));
+            }
+            Elem::Separator => {}
+        }
+    }
+}
+
+/// The fields of the given layout which are currently shown. Hidden fields are not validated.
+pub fn visible_fields<T: CrudDataTrait>(elements: &[Elem<T>], entity: &T) -> Vec<T::Field> {
+    let mut fields = Vec::new();
+    collect_visible_fields(elements, entity, &mut fields);
+    fields
+}
+
+fn collect_visible_fields<T: CrudDataTrait>(elements: &[Elem<T>], entity: &T, fields: &mut Vec<T::Field>) {
+    let collect_group = |group: &Group<T>, fields: &mut Vec<T::Field>| {
+        if holds(&group.visible_if, entity) {
+            collect_visible_fields(&group.children, entity, fields);
+        }
+    };
+    for elem in elements {
+        match elem {
+            Elem::Enclosing(Enclosing::None(group)) | Elem::Enclosing(Enclosing::Card(group)) => {
+                collect_group(group, fields)
+            }
+            Elem::Enclosing(Enclosing::Tabs(tabs)) => {
+                for tab in tabs {
+                    collect_group(&tab.group, fields);
+                }
+            }
+            Elem::Field((field, options)) => {
+                if holds(&options.visible_if, entity) {
+                    fields.push(field.clone());
+                }
+            }
+            Elem::Separator => {}
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use yew::{Callback, KeyboardEvent};
+
+use crate::{CrudDataTrait, Value};
+
+/// Changes of the same text field made within this many milliseconds are undone as one.
+const COALESCE_TEXT_EDITS_WITHIN_MILLIS: f64 = 1000.0;
+
+/// Tracks whether the input of a form is 'dirty': The current input is not the state the form started with.
+/// Used by `CrudCreateView` and `CrudEditView`. Every change of the dirty state is reported to `on_dirty_changed`,
+/// so that the `CrudInstance` can protect the user from leaving the form and losing the changes.
+///
+/// Also records the changes made by the user, so that they can be undone and redone.
+#[derive(Debug, Clone)]
+pub struct FormState<T: CrudDataTrait> {
+    /// `None` until the form has something to start with, e.g. while the entity to edit is still loading.
+    initial: Option<T>,
+    dirty: bool,
+    on_dirty_changed: Callback<bool>,
+    undo_stack: Vec<Change<T>>,
+    redo_stack: Vec<Change<T>>,
+}
+
+#[derive(Debug, Clone)]
+struct Change<T: CrudDataTrait> {
+    field: T::Field,
+    before: Value,
+    after: Value,
+    /// Milliseconds since the unix epoch.
+    at: f64,
+}
+
+impl<T: CrudDataTrait> FormState<T> {
+    pub fn new(on_dirty_changed: Callback<bool>) -> Self {
+        Self {
+            initial: None,
+            dirty: false,
+            on_dirty_changed,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Starts over with the given state, e.g. after the entity was loaded or saved.
+    /// The form is no longer dirty and the history of changes is dropped.
+    pub fn reset(&mut self, initial: T) {
+        self.initial = Some(initial);
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.set_dirty(false);
+    }
+
+    /// Compares the given input with the initial state. Returns whether the dirty state changed.
+    pub fn update(&mut self, input: &T) -> bool {
+        let dirty = self.initial.as_ref().map_or(false, |initial| initial != input);
+        self.set_dirty(dirty)
+    }
+
+    /// Gives up all changes, as the user decided to leave the form anyway.
+    pub fn discard(&mut self) {
+        self.set_dirty(false);
+    }
+
+    /// Records a change made by the user. Anything undone before can no longer be redone.
+    /// Typing into a text field produces a change per keystroke. These are merged into a single change.
+    pub fn record(&mut self, field: T::Field, before: Value, after: Value) {
+        let at = js_sys::Date::now();
+        self.redo_stack.clear();
+        if let Some(last) = self.undo_stack.last_mut() {
+            let is_text = matches!(
+                after,
+                Value::String(_) | Value::Text(_) | Value::OptionalString(Some(_)) | Value::OptionalText(Some(_))
+            );
+            if is_text && last.field == field && at - last.at < COALESCE_TEXT_EDITS_WITHIN_MILLIS {
+                last.after = after;
+                last.at = at;
+                return;
+            }
+        }
+        self.undo_stack.push(Change {
+            field,
+            before,
+            after,
+            at,
+        });
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Takes back the latest change. Returns the field and the value it must be set to.
+    pub fn undo(&mut self) -> Option<(T::Field, Value)> {
+        let change = self.undo_stack.pop()?;
+        let undone = (change.field.clone(), change.before.clone());
+        self.redo_stack.push(change);
+        Some(undone)
+    }
+
+    /// Reapplies the latest undone change. Returns the field and the value it must be set to.
+    pub fn redo(&mut self) -> Option<(T::Field, Value)> {
+        let mut change = self.redo_stack.pop()?;
+        let redone = (change.field.clone(), change.after.clone());
+        // A change made right after redoing must not be merged into the redone change.
+        change.at = f64::MIN;
+        self.undo_stack.push(change);
+        Some(redone)
+    }
+
+    /// Undoes on Ctrl+Z and redoes on Ctrl+Shift+Z or Ctrl+Y. Must be attached as `onkeydown` of the form root,
+    /// so that only key presses within this form are handled.
+    /// A key press is only taken over if there is something to undo or redo. Otherwise, the browsers own undo,
+    /// e.g. of a text input, stays in effect. The view must be rendered again whenever `can_undo` or `can_redo` change.
+    pub fn history_shortcuts(&self, on_undo: Callback<()>, on_redo: Callback<()>) -> Callback<KeyboardEvent> {
+        let (can_undo, can_redo) = (self.can_undo(), self.can_redo());
+        Callback::from(move |event: KeyboardEvent| {
+            if !(event.ctrl_key() || event.meta_key()) || event.alt_key() {
+                return;
+            }
+            let key = event.key().to_lowercase();
+            let callback = match (key.as_str(), event.shift_key()) {
+                ("z", false) if can_undo => &on_undo,
+                ("z", true) | ("y", false) if can_redo => &on_redo,
+                _ => return,
+            };
+            event.prevent_default();
+            // A surrounding form must not undo its own changes as well.
+            event.stop_propagation();
+            callback.emit(());
+        })
+    }
+
+    fn set_dirty(&mut self, dirty: bool) -> bool {
+        let changed = self.dirty != dirty;
+        if changed {
+            self.dirty = dirty;
+            self.on_dirty_changed.emit(dirty);
+        }
+        changed
+    }
+}
@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Value;
+
+const BYTE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// How a value is presented when it is only displayed, for example in a table cell or on a card.
+/// The default formatter displays every value as is.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Formatter {
+    #[serde(default)]
+    pub format: Format,
+    /// Texts longer than this many characters are cut off. The full text is shown as a tooltip.
+    #[serde(default)]
+    pub truncate: Option<usize>,
+    /// Shown instead of empty values: `None`, empty strings and empty selections.
+    #[serde(default)]
+    pub empty: Option<String>,
+    #[serde(default)]
+    pub separators: Separators,
+}
+
+/// Characters used when formatting numbers. Defaults to the german notation, as in "1.234,5".
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Separators {
+    pub decimal: char,
+    pub thousands: char,
+}
+
+impl Default for Separators {
+    fn default() -> Self {
+        Self {
+            decimal: ',',
+            thousands: '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Format {
+    /// Values are displayed as is.
+    Default,
+    Number(NumberFormat),
+    /// The currency symbol is appended to the number, as in "1.234,50 €".
+    Currency {
+        symbol: String,
+        number: NumberFormat,
+    },
+    /// The unit is appended to the number, as in "12,5 kg".
+    Unit {
+        unit: String,
+        number: NumberFormat,
+    },
+    /// Byte counts, scaled to the largest fitting binary unit, as in "1,5 MiB".
+    Bytes { decimals: u8 },
+    /// Fractions, displayed as percentage. A value of 0.5 is displayed as "50 %".
+    Percentage(NumberFormat),
+    Bool(BoolFormat),
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumberFormat {
+    /// Numbers are rounded to this many decimals. Numbers are displayed with all their decimals if `None`.
+    pub decimals: Option<u8>,
+    pub thousands_separator: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimals: None,
+            thousands_separator: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BoolFormat {
+    Icons,
+    Text { yes: String, no: String },
+}
+
+/// The result of applying a `Formatter` to a value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Formatted {
+    Text {
+        text: String,
+        /// The full text, if `text` was truncated.
+        title: Option<String>,
+    },
+    Icon(bool),
+}
+
+impl Formatter {
+    /// Formats the given value.
+    /// Returns `None` if this formatter does not apply to the value, which should then be displayed as usual.
+    pub fn format(&self, value: &Value) -> Option<Formatted> {
+        if let Some(empty) = &self.empty {
+            if value.is_empty() {
+                return Some(Formatted::Text {
+                    text: empty.clone(),
+                    title: None,
+                });
+            }
+        }
+
+        let text = match &self.format {
+            Format::Default => match value {
+                Value::String(_) | Value::Text(_) | Value::OptionalString(_) | Value::OptionalText(_)
+                    if self.truncate.is_some() =>
+                {
+                    Some(value.to_string())
+                }
+                _ => None,
+            },
+            Format::Number(number) => as_number(value).map(|(it, display)| format_number(it, display, number, self.separators)),
+            Format::Currency { symbol, number } => {
+                as_number(value).map(|(it, display)| format!("{} {symbol}", format_number(it, display, number, self.separators)))
+            }
+            Format::Unit { unit, number } => {
+                as_number(value).map(|(it, display)| format!("{} {unit}", format_number(it, display, number, self.separators)))
+            }
+            Format::Bytes { decimals } => as_number(value).map(|(it, _display)| format_bytes(it, *decimals, self.separators)),
+            Format::Percentage(number) => as_number(value).map(|(it, display)| {
+                let percentage = it * 100.0;
+                let display = match number.decimals {
+                    Some(_) => display,
+                    // Avoid displaying floating point noise, as in "14.000000000000002 %".
+                    None => ((percentage * 1e6).round() / 1e6).to_string(),
+                };
+                format!("{} %", format_number(percentage, display, number, self.separators))
+            }),
+            Format::Bool(bool_format) => match (value, bool_format) {
+                (Value::Bool(value), BoolFormat::Icons) => return Some(Formatted::Icon(*value)),
+                (Value::Bool(value), BoolFormat::Text { yes, no }) => Some(match value {
+                    true => yes.clone(),
+                    false => no.clone(),
+                }),
+                _ => None,
+            },
+        }?;
+
+        Some(match self.truncate {
+            Some(max) if text.chars().count() > max => Formatted::Text {
+                text: format!("{}…", text.chars().take(max).collect::<String>()),
+                title: Some(text),
+            },
+            _ => Formatted::Text { text, title: None },
+        })
+    }
+}
+
+/// The numeric value, together with its default string representation.
+fn as_number(value: &Value) -> Option<(f64, String)> {
+    value.as_f64().map(|number| (number, value.to_string()))
+}
+
+/// `display` is used as is if no rounding is requested, as converted floats are imprecise.
+fn format_number(number: f64, display: String, format: &NumberFormat, separators: Separators) -> String {
+    let digits = match format.decimals {
+        Some(decimals) => format!("{:.*}", decimals as usize, number),
+        None => display,
+    };
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits.as_str()),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+
+    let mut formatted = String::from(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if format.thousands_separator && i > 0 && (integer.len() - i) % 3 == 0 {
+            formatted.push(separators.thousands);
+        }
+        formatted.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        formatted.push(separators.decimal);
+        formatted.push_str(fraction);
+    }
+    formatted
+}
+
+fn format_bytes(bytes: f64, decimals: u8, separators: Separators) -> String {
+    let mut scaled = bytes;
+    let mut unit = 0;
+    while scaled.abs() >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+        scaled /= 1024.0;
+        unit += 1;
+    }
+    let number = NumberFormat {
+        // Bytes are never fractional.
+        decimals: Some(if unit == 0 { 0 } else { decimals }),
+        thousands_separator: true,
+    };
+    format!("{} {}", format_number(scaled, String::new(), &number, separators), BYTE_UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(decimals: Option<u8>) -> NumberFormat {
+        NumberFormat {
+            decimals,
+            thousands_separator: true,
+        }
+    }
+
+    fn text(formatter: &Formatter, value: Value) -> Option<String> {
+        match formatter.format(&value)? {
+            Formatted::Text { text, .. } => Some(text),
+            Formatted::Icon(_) => None,
+        }
+    }
+
+    #[test]
+    fn rounds_to_decimals() {
+        let separators = Separators::default();
+        assert_eq!(format_number(1.005, String::new(), &number(Some(0)), separators), "1");
+        assert_eq!(format_number(2.5, String::new(), &number(Some(1)), separators), "2,5");
+        assert_eq!(format_number(2.345, String::new(), &number(Some(2)), separators), "2,35");
+        assert_eq!(format_number(7.0, String::new(), &number(Some(2)), separators), "7,00");
+    }
+
+    #[test]
+    fn keeps_display_without_decimals() {
+        let separators = Separators::default();
+        assert_eq!(format_number(0.1, "0.1".to_owned(), &number(None), separators), "0,1");
+        assert_eq!(format_number(42.0, "42".to_owned(), &number(None), separators), "42");
+    }
+
+    #[test]
+    fn formats_negative_numbers() {
+        let separators = Separators::default();
+        assert_eq!(format_number(-1234.5, String::new(), &number(Some(1)), separators), "-1.234,5");
+        assert_eq!(format_number(-12.0, String::new(), &number(Some(0)), separators), "-12");
+        assert_eq!(format_number(-123456.0, String::new(), &number(Some(0)), separators), "-123.456");
+    }
+
+    #[test]
+    fn inserts_thousands_separators() {
+        let separators = Separators::default();
+        assert_eq!(format_number(999.0, String::new(), &number(Some(0)), separators), "999");
+        assert_eq!(format_number(1000.0, String::new(), &number(Some(0)), separators), "1.000");
+        assert_eq!(format_number(1234567.891, String::new(), &number(Some(2)), separators), "1.234.567,89");
+        let without = NumberFormat {
+            decimals: Some(0),
+            thousands_separator: false,
+        };
+        assert_eq!(format_number(1234567.0, String::new(), &without, separators), "1234567");
+    }
+
+    #[test]
+    fn uses_configured_separators() {
+        let separators = Separators {
+            decimal: '.',
+            thousands: ',',
+        };
+        assert_eq!(format_number(1234567.5, String::new(), &number(Some(1)), separators), "1,234,567.5");
+        assert_eq!(format_bytes(1536.0, 1, separators), "1.5 KiB");
+    }
+
+    #[test]
+    fn steps_through_byte_units() {
+        let separators = Separators::default();
+        assert_eq!(format_bytes(0.0, 1, separators), "0 B");
+        assert_eq!(format_bytes(1023.0, 1, separators), "1.023 B");
+        assert_eq!(format_bytes(1024.0, 1, separators), "1,0 KiB");
+        assert_eq!(format_bytes(1536.0, 1, separators), "1,5 KiB");
+        assert_eq!(format_bytes(1024.0 * 1024.0, 2, separators), "1,00 MiB");
+        assert_eq!(format_bytes(3.0 * 1024.0 * 1024.0 * 1024.0, 0, separators), "3 GiB");
+        assert_eq!(format_bytes(2048.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0, 0, separators), "2.048 TiB");
+        assert_eq!(format_bytes(-2048.0, 0, separators), "-2 KiB");
+    }
+
+    #[test]
+    fn formats_values() {
+        let formatter = Formatter {
+            format: Format::Currency {
+                symbol: "€".to_owned(),
+                number: number(Some(2)),
+            },
+            ..Default::default()
+        };
+        assert_eq!(text(&formatter, Value::F64(1234.5)), Some("1.234,50 €".to_owned()));
+        assert_eq!(text(&formatter, Value::Bool(true)), None);
+
+        let formatter = Formatter {
+            format: Format::Percentage(number(None)),
+            ..Default::default()
+        };
+        assert_eq!(text(&formatter, Value::F64(0.14)), Some("14 %".to_owned()));
+
+        let formatter = Formatter {
+            format: Format::Number(number(Some(0))),
+            separators: Separators {
+                decimal: '.',
+                thousands: '\'',
+            },
+            ..Default::default()
+        };
+        assert_eq!(text(&formatter, Value::I32(-1234567)), Some("-1'234'567".to_owned()));
+    }
+}
@@ -1,6 +1,13 @@
+pub mod computed_field;
 pub mod custom_field;
+pub mod duration;
+pub mod field_condition;
 pub mod files;
+pub mod form_state;
+pub mod formatter;
+pub mod permissions;
 pub mod toasts;
+pub mod validation;
 
 use std::collections::HashMap;
 
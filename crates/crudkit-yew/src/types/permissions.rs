@@ -0,0 +1,152 @@
+use std::{fmt::Debug, rc::Rc};
+
+use crate::{CrudDataTrait, CrudFieldNameTrait, CrudMainTrait, CrudSimpleView, Elem, Enclosing, Group, Tab};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityOperation {
+    Read,
+    Edit,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldAccess {
+    /// The field is not shown at all.
+    Hidden,
+    /// The field is shown but can not be changed.
+    ReadOnly,
+    Editable,
+}
+
+/// Decides what the user of a `CrudInstance` may see and do.
+/// Every decision defaults to "allowed". Implementors only override what they want to restrict.
+///
+/// The UI merely hides what the backend would reject anyway. It is no replacement for access checks on the server!
+/// Implementations may base their decisions on the current user, for example by consulting `T::AuthProvider`.
+pub trait CrudPermissionPolicy<T: CrudMainTrait> {
+    /// Whether the given view may be opened at all.
+    fn view_allowed(&self, _view: &CrudSimpleView) -> bool {
+        true
+    }
+
+    /// Whether the given operation may be performed on a specific entity.
+    fn entity_allowed(&self, _entity: &T::UpdateModel, _operation: EntityOperation) -> bool {
+        true
+    }
+
+    /// How the field with the given name is presented in the given view.
+    /// Fields are identified by name, as create, read and update models use different field types.
+    fn field_access(&self, _view: &CrudSimpleView, _field_name: &str) -> FieldAccess {
+        FieldAccess::Editable
+    }
+
+    /// Whether the `CrudAction` with the given id is available.
+    fn action_allowed(&self, _action_id: &str) -> bool {
+        true
+    }
+
+    /// Whether the `CrudEntityAction` with the given id is available for a specific entity.
+    fn entity_action_allowed(&self, _action_id: &str, _entity: &T::UpdateModel) -> bool {
+        true
+    }
+}
+
+/// Allows everything.
+pub struct AllowAll;
+
+impl<T: CrudMainTrait> CrudPermissionPolicy<T> for AllowAll {}
+
+#[derive(Clone)]
+pub struct PermissionPolicy<T: CrudMainTrait> {
+    pub policy: Rc<dyn CrudPermissionPolicy<T>>,
+}
+
+impl<T: CrudMainTrait> PermissionPolicy<T> {
+    pub fn new(policy: impl CrudPermissionPolicy<T> + 'static) -> Self {
+        Self {
+            policy: Rc::new(policy),
+        }
+    }
+
+    pub fn view_allowed(&self, view: &CrudSimpleView) -> bool {
+        self.policy.view_allowed(view)
+    }
+
+    pub fn entity_allowed(&self, entity: &T::UpdateModel, operation: EntityOperation) -> bool {
+        self.policy.entity_allowed(entity, operation)
+    }
+
+    pub fn field_access(&self, view: &CrudSimpleView, field_name: &str) -> FieldAccess {
+        self.policy.field_access(view, field_name)
+    }
+
+    pub fn action_allowed(&self, action_id: &str) -> bool {
+        self.policy.action_allowed(action_id)
+    }
+
+    pub fn entity_action_allowed(&self, action_id: &str, entity: &T::UpdateModel) -> bool {
+        self.policy.entity_action_allowed(action_id, entity)
+    }
+
+    /// Removes hidden fields from the given layout and disables read-only fields.
+    pub fn restrict_elements<M: CrudDataTrait>(
+        &self,
+        view: &CrudSimpleView,
+        elements: &[Elem<M>],
+    ) -> Vec<Elem<M>> {
+        elements
+            .iter()
+            .filter_map(|elem| match elem {
+                Elem::Enclosing(enclosing) => Some(Elem::Enclosing(match enclosing {
+                    Enclosing::None(group) => Enclosing::None(self.restrict_group(view, group)),
+                    Enclosing::Tabs(tabs) => Enclosing::Tabs(
+                        tabs.iter()
+                            .map(|tab| Tab {
+                                label: tab.label.clone(),
+                                group: self.restrict_group(view, &tab.group),
+                            })
+                            .collect(),
+                    ),
+                    Enclosing::Card(group) => Enclosing::Card(self.restrict_group(view, group)),
+                })),
+                Elem::Field((field, options)) => match self.field_access(view, field.get_name()) {
+                    FieldAccess::Hidden => None,
+                    FieldAccess::ReadOnly => {
+                        let mut options = options.clone();
+                        options.disabled = true;
+                        Some(Elem::Field((field.clone(), options)))
+                    }
+                    FieldAccess::Editable => Some(elem.clone()),
+                },
+                Elem::Separator => Some(Elem::Separator),
+            })
+            .collect()
+    }
+
+    fn restrict_group<M: CrudDataTrait>(&self, view: &CrudSimpleView, group: &Group<M>) -> Group<M> {
+        Group {
+            layout: group.layout.clone(),
+            children: self.restrict_elements(view, &group.children),
+            visible_if: group.visible_if.clone(),
+            enabled_if: group.enabled_if.clone(),
+        }
+    }
+}
+
+impl<T: CrudMainTrait> Default for PermissionPolicy<T> {
+    fn default() -> Self {
+        Self::new(AllowAll)
+    }
+}
+
+impl<T: CrudMainTrait> Debug for PermissionPolicy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PermissionPolicy").finish()
+    }
+}
+
+impl<T: CrudMainTrait> PartialEq for PermissionPolicy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.policy, &other.policy)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Value;
+
+/// A client-side check of a single field value.
+/// Validators are usually declared on model fields, e.g. `#[ck_field_value(validate(required, range(0, 100)))]`,
+/// and run whenever the user changes the value of the field. Violations block saving the entity.
+///
+/// Client-side validation only exists for a quicker feedback. The server must validate the entity anyway!
+#[derive(Debug, Clone, PartialEq)]
+pub enum Validator {
+    /// The value must not be empty. See `Value::is_empty`.
+    Required,
+    /// Strings must have at least this many characters.
+    MinLen(usize),
+    /// Strings must have at most this many characters.
+    MaxLen(usize),
+    /// Numbers must lie in this range (inclusive).
+    Range { min: f64, max: f64 },
+    /// Strings must match this pattern completely. Uses JavaScript regular expression syntax.
+    Regex(String),
+}
+
+impl Validator {
+    pub fn validate(&self, value: &Value) -> Result<(), String> {
+        match self {
+            Validator::Required => match value.is_empty() {
+                true => Err("Dieses Feld ist erforderlich.".to_owned()),
+                false => Ok(()),
+            },
+            Validator::MinLen(min) => match as_str(value) {
+                Some(string) if string.chars().count() < *min => {
+                    Err(format!("Mindestens {min} Zeichen erforderlich."))
+                }
+                _ => Ok(()),
+            },
+            Validator::MaxLen(max) => match as_str(value) {
+                Some(string) if string.chars().count() > *max => {
+                    Err(format!("Höchstens {max} Zeichen erlaubt."))
+                }
+                _ => Ok(()),
+            },
+            Validator::Range { min, max } => match value.as_f64() {
+                Some(number) if number < *min || number > *max => {
+                    Err(format!("Der Wert muss zwischen {min} und {max} liegen."))
+                }
+                _ => Ok(()),
+            },
+            Validator::Regex(pattern) => match as_str(value) {
+                // Empty values are the business of `Required`.
+                Some(string) if !string.is_empty() => {
+                    let regex = js_sys::RegExp::new(&format!("^(?:{pattern})$"), "u");
+                    match regex.test(string) {
+                        true => Ok(()),
+                        false => Err("Die Eingabe hat nicht das erwartete Format.".to_owned()),
+                    }
+                }
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+fn as_str(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(string) | Value::Text(string) => Some(string),
+        Value::OptionalString(Some(string)) | Value::OptionalText(Some(string)) => Some(string),
+        _ => None,
+    }
+}
@@ -1,13 +1,13 @@
 #![forbid(unsafe_code)]
 #![deny(clippy::unwrap_used)]
 
-use darling::*;
+use darling::{util::Flag, *};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use proc_macro_error::{abort, proc_macro_error};
 use quote::quote;
 use serde::Deserialize;
-use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Lit, NestedMeta};
 
 // TODO: Merge FieldValue into Field, only use "field" attribute!
 
@@ -29,6 +29,73 @@ struct MyFieldReceiver {
     #[darling(rename = "type")]
     #[darling(map = "parse_type")]
     value_type: Option<ValueType>,
+
+    validate: Option<Validations>,
+}
+
+/// Parsed from `validate(required, min_len = 3, max_len = 10, range(0, 100), regex = "...")`.
+#[derive(Debug, Default, FromMeta)]
+struct Validations {
+    required: Flag,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    range: Option<ValidationRange>,
+    regex: Option<String>,
+}
+
+impl Validations {
+    /// Expressions constructing the `Validator`s declared.
+    fn validators(&self) -> Vec<proc_macro2::TokenStream> {
+        let mut validators = Vec::new();
+        if self.required.is_present() {
+            validators.push(quote! { crudkit_yew::types::validation::Validator::Required });
+        }
+        if let Some(min_len) = self.min_len {
+            validators.push(quote! { crudkit_yew::types::validation::Validator::MinLen(#min_len) });
+        }
+        if let Some(max_len) = self.max_len {
+            validators.push(quote! { crudkit_yew::types::validation::Validator::MaxLen(#max_len) });
+        }
+        if let Some(ValidationRange { min, max }) = &self.range {
+            let min = proc_macro2::Literal::f64_unsuffixed(*min);
+            let max = proc_macro2::Literal::f64_unsuffixed(*max);
+            validators.push(quote! { crudkit_yew::types::validation::Validator::Range { min: #min, max: #max } });
+        }
+        if let Some(regex) = &self.regex {
+            validators.push(quote! { crudkit_yew::types::validation::Validator::Regex(#regex.to_owned()) });
+        }
+        validators
+    }
+}
+
+/// Parsed from `range(min, max)`.
+#[derive(Debug)]
+struct ValidationRange {
+    min: f64,
+    max: f64,
+}
+
+impl FromMeta for ValidationRange {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        fn number(item: &NestedMeta) -> darling::Result<f64> {
+            match item {
+                NestedMeta::Lit(Lit::Int(int)) => int
+                    .base10_parse::<f64>()
+                    .map_err(|err| darling::Error::custom(err).with_span(int)),
+                NestedMeta::Lit(Lit::Float(float)) => float
+                    .base10_parse::<f64>()
+                    .map_err(|err| darling::Error::custom(err).with_span(float)),
+                other => Err(darling::Error::custom("expected a number").with_span(other)),
+            }
+        }
+        match items {
+            [min, max] => Ok(Self {
+                min: number(min)?,
+                max: number(max)?,
+            }),
+            _ => Err(darling::Error::custom("expected `range(min, max)`")),
+        }
+    }
 }
 
 impl MyFieldReceiver {
@@ -95,12 +162,13 @@ pub fn store(input: TokenStream) -> TokenStream {
             ValueType::String => quote! { entity.#field_ident.clone() },
             ValueType::Text => quote! { entity.#field_ident.clone() },
             ValueType::Json => quote! { crudkit_yew::JsonValue::new(entity.#field_ident.clone()) },
-            ValueType::OptionalText => quote! { entity.#field_ident.clone().unwrap_or_default() },
-            // We use .unwrap_or_default(), as we feed that string into Value::String (see From<ValueType>). We should get rid of this.
-            ValueType::OptionalString => quote! { entity.#field_ident.clone().unwrap_or_default() },
+            ValueType::OptionalText => quote! { entity.#field_ident.clone() },
+            ValueType::OptionalString => quote! { entity.#field_ident.clone() },
             ValueType::OptionalJson => quote! { entity.#field_ident.clone().map(|it| crudkit_yew::JsonValue::new(it)) },
             ValueType::UuidV4 => quote! { entity.#field_ident },
             ValueType::UuidV7 => quote! { entity.#field_ident },
+            ValueType::OptionalUuidV4 => quote! { entity.#field_ident },
+            ValueType::OptionalUuidV7 => quote! { entity.#field_ident },
             ValueType::Bool => quote! { entity.#field_ident },
             ValueType::ValidationStatus => quote! { entity.#field_ident },
             ValueType::I32 => quote! { entity.#field_ident },
@@ -111,10 +179,24 @@ pub fn store(input: TokenStream) -> TokenStream {
             ValueType::OptionalU32 => quote! { entity.#field_ident.clone() },
             ValueType::F32 => quote! { entity.#field_ident },
             ValueType::F64 => quote! { entity.#field_ident },
+            ValueType::OptionalF32 => quote! { entity.#field_ident },
+            ValueType::OptionalF64 => quote! { entity.#field_ident },
+            ValueType::Decimal => quote! { entity.#field_ident },
+            ValueType::OptionalDecimal => quote! { entity.#field_ident },
             ValueType::PrimitiveDateTime => quote! { entity.#field_ident.clone() },
             ValueType::OffsetDateTime => quote! { entity.#field_ident.clone() },
             ValueType::OptionalPrimitiveDateTime => quote! { entity.#field_ident.clone() },
             ValueType::OptionalOffsetDateTime => quote! { entity.#field_ident.clone() },
+            ValueType::Date => quote! { entity.#field_ident },
+            ValueType::OptionalDate => quote! { entity.#field_ident },
+            ValueType::Time => quote! { entity.#field_ident },
+            ValueType::OptionalTime => quote! { entity.#field_ident },
+            ValueType::Duration => quote! { entity.#field_ident },
+            ValueType::OptionalDuration => quote! { entity.#field_ident },
+            ValueType::StringVec => quote! { entity.#field_ident.clone() },
+            ValueType::I32Vec => quote! { entity.#field_ident.clone() },
+            ValueType::I64Vec => quote! { entity.#field_ident.clone() },
+            ValueType::UuidVec => quote! { entity.#field_ident.clone() },
             ValueType::Select => quote! { entity.#field_ident.clone().into() },
             ValueType::Multiselect => quote! { entity.#field_ident.clone().into() },
             ValueType::OptionalSelect => quote! { entity.#field_ident.clone().map(Into::into) },
@@ -157,14 +239,15 @@ pub fn store(input: TokenStream) -> TokenStream {
             ValueType::String => quote! { entity.#field_ident = value.take_string() },
             ValueType::Text => quote! { entity.#field_ident = value.take_string() },
             ValueType::Json => quote! { entity.#field_ident = value.take_inner_json_value() },
-            ValueType::OptionalText => quote! { entity.#field_ident = std::option::Option::Some(value.take_string()) },
-            // TODO: value should contain Option. do not force Some type...
-            ValueType::OptionalString => quote! { entity.#field_ident = std::option::Option::Some(value.take_string()) },
+            ValueType::OptionalText => quote! { entity.#field_ident = value.take_optional_text() },
+            ValueType::OptionalString => quote! { entity.#field_ident = value.take_optional_string() },
             ValueType::OptionalJson => {
                 quote! { entity.#field_ident = std::option::Option::Some(value.take_inner_json_value()) }
             }
             ValueType::UuidV4 => quote! { entity.#field_ident = value.to_uuid_v4() },
             ValueType::UuidV7 => quote! { entity.#field_ident = value.to_uuid_v7() },
+            ValueType::OptionalUuidV4 => quote! { entity.#field_ident = value.take_optional_uuid_v4() },
+            ValueType::OptionalUuidV7 => quote! { entity.#field_ident = value.take_optional_uuid_v7() },
             ValueType::Bool => quote! { entity.#field_ident = value.take_bool() },
             ValueType::ValidationStatus => quote! { entity.#field_ident = value.take_bool() },
             ValueType::I32 => quote! { entity.#field_ident = value.take_i32() },
@@ -175,10 +258,24 @@ pub fn store(input: TokenStream) -> TokenStream {
             ValueType::OptionalU32 => quote! { entity.#field_ident = value.take_optional_u32() },
             ValueType::F32 => quote! { entity.#field_ident = value.take_f32() },
             ValueType::F64 => quote! { entity.#field_ident = value.take_f64() },
+            ValueType::OptionalF32 => quote! { entity.#field_ident = value.take_optional_f32() },
+            ValueType::OptionalF64 => quote! { entity.#field_ident = value.take_optional_f64() },
+            ValueType::Decimal => quote! { entity.#field_ident = value.take_decimal() },
+            ValueType::OptionalDecimal => quote! { entity.#field_ident = value.take_optional_decimal() },
             ValueType::PrimitiveDateTime => quote! { entity.#field_ident = value.take_primitive_date_time() },
             ValueType::OffsetDateTime => quote! { entity.#field_ident = value.take_offset_date_time() },
             ValueType::OptionalPrimitiveDateTime => quote! { entity.#field_ident = value.take_optional_primitive_date_time() },
             ValueType::OptionalOffsetDateTime => quote! { entity.#field_ident = value.take_optional_offset_date_time() },
+            ValueType::Date => quote! { entity.#field_ident = value.take_date() },
+            ValueType::OptionalDate => quote! { entity.#field_ident = value.take_optional_date() },
+            ValueType::Time => quote! { entity.#field_ident = value.take_time() },
+            ValueType::OptionalTime => quote! { entity.#field_ident = value.take_optional_time() },
+            ValueType::Duration => quote! { entity.#field_ident = value.take_duration() },
+            ValueType::OptionalDuration => quote! { entity.#field_ident = value.take_optional_duration() },
+            ValueType::StringVec => quote! { entity.#field_ident = value.take_string_vec() },
+            ValueType::I32Vec => quote! { entity.#field_ident = value.take_i32_vec() },
+            ValueType::I64Vec => quote! { entity.#field_ident = value.take_i64_vec() },
+            ValueType::UuidVec => quote! { entity.#field_ident = value.take_uuid_vec() },
             ValueType::Select => quote! { entity.#field_ident = value.take_select_downcast_to::<#field_ty>().into() },
             ValueType::Multiselect => quote! { entity.#field_ident = value.take_multiselect_downcast_to().into() },
             ValueType::OptionalSelect => quote! { entity.#field_ident = value.take_optional_select_downcast_to().into() },
@@ -208,6 +305,36 @@ pub fn store(input: TokenStream) -> TokenStream {
         },
     };
 
+    // Self::Name => vec![crudkit_yew::types::validation::Validator::Required],
+    // Only generated if at least one field declares validators. The trait provides an empty default otherwise.
+    let validators_impl = match input.fields().iter().any(|field| field.validate.is_some()) {
+        false => quote! {},
+        true => {
+            let validators_arms = input.fields().iter().map(|field| {
+                let field_ident = field.ident.as_ref().expect("Expected named field!");
+                let field_name = field_ident.to_string();
+                let field_name_as_type_name = field_name_as_type_name(&field_name);
+                let field_name_as_type_ident =
+                    Ident::new(field_name_as_type_name.as_str(), Span::call_site());
+                let validators = field
+                    .validate
+                    .as_ref()
+                    .map(Validations::validators)
+                    .unwrap_or_default();
+                quote! {
+                    #field_enum_ident::#field_name_as_type_ident => vec![#(#validators),*]
+                }
+            });
+            quote! {
+                fn validators(&self) -> Vec<crudkit_yew::types::validation::Validator> {
+                    match self {
+                        #(#validators_arms),*,
+                    }
+                }
+            }
+        }
+    };
+
     quote! {
         impl crudkit_yew::CrudFieldValueTrait<#ident> for #field_enum_ident {
             fn get_value(&self, entity: &#ident) -> crudkit_yew::Value {
@@ -217,6 +344,8 @@ pub fn store(input: TokenStream) -> TokenStream {
             fn set_value(&self, entity: &mut #ident, value: crudkit_yew::Value) {
                 #set_value_impl
             }
+
+            #validators_impl
         }
     }
     .into()
@@ -232,6 +361,8 @@ enum ValueType {
     OptionalJson,
     UuidV4,
     UuidV7,
+    OptionalUuidV4,
+    OptionalUuidV7,
     Bool,
     ValidationStatus,
     I32,
@@ -242,10 +373,24 @@ enum ValueType {
     OptionalU32,
     F32,
     F64,
+    OptionalF32,
+    OptionalF64,
+    Decimal,
+    OptionalDecimal,
     PrimitiveDateTime,
     OffsetDateTime,
     OptionalPrimitiveDateTime,
     OptionalOffsetDateTime,
+    Date,
+    OptionalDate,
+    Time,
+    OptionalTime,
+    Duration,
+    OptionalDuration,
+    StringVec,
+    I32Vec,
+    I64Vec,
+    UuidVec,
     Select,
     Multiselect,
     OptionalSelect,
@@ -261,13 +406,15 @@ impl From<ValueType> for Ident {
         Ident::new(
             match value_type {
                 ValueType::String => "String",
-                ValueType::OptionalString => "String",
+                ValueType::OptionalString => "OptionalString",
                 ValueType::Text => "Text",
-                ValueType::OptionalText => "Text",
+                ValueType::OptionalText => "OptionalText",
                 ValueType::Json => "Json",
                 ValueType::OptionalJson => "OptionalJson",
                 ValueType::UuidV4 => "UuidV4",
                 ValueType::UuidV7 => "UuidV7",
+                ValueType::OptionalUuidV4 => "OptionalUuidV4",
+                ValueType::OptionalUuidV7 => "OptionalUuidV7",
                 ValueType::Bool => "Bool",
                 ValueType::ValidationStatus => "ValidationStatus",
                 ValueType::I32 => "I32",
@@ -278,10 +425,24 @@ impl From<ValueType> for Ident {
                 ValueType::OptionalU32 => "OptionalU32",
                 ValueType::F32 => "F32",
                 ValueType::F64 => "F64",
+                ValueType::OptionalF32 => "OptionalF32",
+                ValueType::OptionalF64 => "OptionalF64",
+                ValueType::Decimal => "Decimal",
+                ValueType::OptionalDecimal => "OptionalDecimal",
                 ValueType::PrimitiveDateTime => "PrimitiveDateTime",
                 ValueType::OffsetDateTime => "OffsetDateTime",
                 ValueType::OptionalPrimitiveDateTime => "OptionalPrimitiveDateTime",
                 ValueType::OptionalOffsetDateTime => "OptionalOffsetDateTime",
+                ValueType::Date => "Date",
+                ValueType::OptionalDate => "OptionalDate",
+                ValueType::Time => "Time",
+                ValueType::OptionalTime => "OptionalTime",
+                ValueType::Duration => "Duration",
+                ValueType::OptionalDuration => "OptionalDuration",
+                ValueType::StringVec => "StringVec",
+                ValueType::I32Vec => "I32Vec",
+                ValueType::I64Vec => "I64Vec",
+                ValueType::UuidVec => "UuidVec",
                 ValueType::Select => "Select",
                 ValueType::Multiselect => "Multiselect",
                 ValueType::OptionalSelect => "OptionalSelect",
@@ -304,19 +465,36 @@ impl From<&syn::Type> for ValueType {
                 "i32" => ValueType::I32,
                 "i64" => ValueType::I64,
                 "f32" => ValueType::F32,
+                "f64" => ValueType::F64,
+                "Decimal" | "rust_decimal::Decimal" => ValueType::Decimal,
                 "String" => ValueType::String,
                 "serde_json::Value" => ValueType::Json,
                 "UuidV4" => ValueType::UuidV4,
                 "UuidV7" => ValueType::UuidV7,
                 "time::PrimitiveDateTime" => ValueType::PrimitiveDateTime,
                 "time::OffsetDateTime" => ValueType::OffsetDateTime,
+                "time::Date" => ValueType::Date,
+                "time::Time" => ValueType::Time,
+                "time::Duration" => ValueType::Duration,
                 "Option<i64>" => ValueType::OptionalI64,
                 "Option<i32>" => ValueType::OptionalI32,
                 "Option<u32>" => ValueType::OptionalU32,
+                "Option<f32>" => ValueType::OptionalF32,
+                "Option<f64>" => ValueType::OptionalF64,
+                "Option<Decimal>" | "Option<rust_decimal::Decimal>" => ValueType::OptionalDecimal,
                 "Option<String>" => ValueType::OptionalString,
+                "Option<UuidV4>" => ValueType::OptionalUuidV4,
+                "Option<UuidV7>" => ValueType::OptionalUuidV7,
                 "Option<serde_json::Value>" => ValueType::OptionalJson,
                 "Option<time::PrimitiveDateTime>" => ValueType::OptionalPrimitiveDateTime,
                 "Option<time::OffsetDateTime>" => ValueType::OptionalOffsetDateTime,
+                "Option<time::Date>" => ValueType::OptionalDate,
+                "Option<time::Time>" => ValueType::OptionalTime,
+                "Option<time::Duration>" => ValueType::OptionalDuration,
+                "Vec<String>" => ValueType::StringVec,
+                "Vec<i32>" => ValueType::I32Vec,
+                "Vec<i64>" => ValueType::I64Vec,
+                "Vec<Uuid>" | "Vec<uuid::Uuid>" => ValueType::UuidVec,
                 other => {
                     let span = ty.span();
                     let message = format!("Unknown type {other:?}. Expected a known type.");
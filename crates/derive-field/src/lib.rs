@@ -164,6 +164,16 @@ pub fn store(input: TokenStream) -> TokenStream {
         },
     };
 
+    let all_fields = input.fields().iter().map(|field| {
+        let name = field.ident.as_ref().expect("Expected named field!");
+        let name = name.to_string();
+        let type_name = field_name_as_type_name(&name);
+        let type_ident = Ident::new(type_name.as_str(), Span::call_site());
+        quote! {
+            #field_name::#type_ident
+        }
+    });
+
     quote! {
         #[derive(PartialEq, Eq, Hash, Clone, Debug, serde::Serialize, serde::Deserialize)]
         pub enum #field_name {
@@ -184,6 +194,10 @@ pub fn store(input: TokenStream) -> TokenStream {
             fn get_field(field_name: &str) -> #field_name {
                 #get_field_impl
             }
+
+            fn get_all_fields() -> Vec<#field_name> {
+                vec![#(#all_fields),*]
+            }
         }
     }
     .into()